@@ -0,0 +1,20 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day1;
+
+// size is the number of pairs. The values come from a range that grows with the lists,
+// so some of them repeat and part 2 has something to count
+impl Generate for Day1 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let high = 10000 + 4 * size as i64;
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            text += &format!(
+                "{}   {}\n",
+                rng.range(10000..=high),
+                rng.range(10000..=high)
+            );
+        }
+        Generated::new(text)
+    }
+}
@@ -0,0 +1,160 @@
+mod generate;
+
+use anyhow::Result;
+use common::{lint, parse, Answer, Lint, Params, ParseError, Solution, Strategy};
+
+pub struct Day1;
+
+pub struct Lists {
+    left: Vec<u64>,
+    right: Vec<u64>,
+    strategy: Distance,
+}
+
+// How part 1 pairs up the two lists
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Distance {
+    // Sort both lists and zip them
+    Sorted,
+    // Repeatedly take the smallest value out of each list - faster in the example but
+    // slower with the full list
+    SmallestFirst,
+}
+
+impl Strategy for Distance {
+    const ALL: &'static [Distance] = &[Distance::Sorted, Distance::SmallestFirst];
+
+    fn name(self) -> &'static str {
+        match self {
+            Distance::Sorted => "sorted",
+            Distance::SmallestFirst => "smallest_first",
+        }
+    }
+}
+
+impl Solution for Day1 {
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let mut list1 = Vec::new();
+        let mut list2 = Vec::new();
+
+        for line in parse::lines(input) {
+            if line.text.trim().is_empty() {
+                continue;
+            }
+            let mut line_iter = line.text.split_whitespace();
+            list1.push(line.number(line_iter.next().unwrap_or(line.end()))?);
+            list2.push(line.number(line_iter.next().unwrap_or(line.end()))?);
+        }
+        Ok(Lists {
+            left: list1,
+            right: list2,
+            strategy: Distance::from_params(params)?,
+        })
+    }
+
+    fn part1(lists: &Self::Input) -> Result<Answer> {
+        let (list1, list2) = (lists.left.clone(), lists.right.clone());
+        let total_distance = match lists.strategy {
+            Distance::Sorted => get_list_distance(list1, list2),
+            Distance::SmallestFirst => get_distance_by_smallest(list1, list2),
+        };
+        Ok(total_distance.into())
+    }
+
+    fn part2(lists: &Self::Input) -> Result<Answer> {
+        let similarity_score = get_list_similarity(&lists.left, &lists.right);
+        Ok(similarity_score.into())
+    }
+}
+
+// Two location IDs a line, nothing else
+impl Lint for Day1 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::each_line(input, |line| {
+            let mut ids = line.text.split_whitespace();
+            for _ in 0..2 {
+                line.number::<u64>(ids.next().unwrap_or(line.end()))?;
+            }
+            match ids.next() {
+                Some(extra) => Err(line.error(extra, "end of line")),
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+fn get_distance_by_smallest(mut list1: Vec<u64>, mut list2: Vec<u64>) -> u64 {
+    let mut total_distance = 0;
+    while !list1.is_empty() && !list2.is_empty() {
+        let a_min = get_smallest_value(&mut list1);
+        let b_min = get_smallest_value(&mut list2);
+        total_distance = {
+            if a_min > b_min {
+                total_distance + a_min - b_min
+            } else {
+                total_distance + b_min - a_min
+            }
+        }
+    }
+    total_distance
+}
+
+fn get_smallest_value(v: &mut Vec<u64>) -> u64 {
+    let mut min_index = 0;
+    let mut min_val = u64::MAX;
+    for (i, val) in v.iter().enumerate() {
+        if min_val > *val {
+            min_val = *val;
+            min_index = i;
+        }
+    }
+    v.remove(min_index)
+}
+
+fn get_list_distance(mut a: Vec<u64>, mut b: Vec<u64>) -> u64 {
+    a.sort_unstable();
+    b.sort_unstable();
+    a.iter().zip(b).fold(0, |sum, (a_val, b_val)| {
+        if a_val > &b_val {
+            sum + a_val - b_val
+        } else {
+            sum + b_val - a_val
+        }
+    })
+}
+
+fn get_list_similarity(a: &[u64], b: &[u64]) -> u64 {
+    let mut similarity_score = 0;
+    for &a_val in a {
+        let score_increase = b.iter().fold(0, |total, b_val| {
+            if a_val == *b_val {
+                total + a_val
+            } else {
+                total
+            }
+        });
+        similarity_score += score_increase;
+    }
+    similarity_score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{random::Rng, strategy, Generate};
+
+    #[test]
+    fn distance_strategies_agree() {
+        for seed in 0..50 {
+            let input = Day1::generate(&mut Rng::new(seed), 40);
+            strategy::check_agreement::<Day1, Distance>(&input.text, &input.params)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day1::Day1;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day1>()?;
+
+    println!("List Distance: {}", Day1::part1(&input)?);
+    println!("Similarity Score: {}", Day1::part2(&input)?);
+
+    Ok(())
+}
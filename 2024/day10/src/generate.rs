@@ -0,0 +1,30 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Grid, GridPoint};
+
+use crate::Day10;
+
+// size is the side of the square map. Random heights almost never make a trail, so a few
+// random walks climbing from 0 to 9 are laid over them
+impl Generate for Day10 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(2);
+        let mut map = Grid::filled((side, side), '0');
+        for point in map.points().collect::<Vec<GridPoint>>() {
+            let height = rng.range(0..=9) as u8;
+            map.set(&point, (b'0' + height) as char);
+        }
+        for _ in 0..side {
+            let mut point =
+                GridPoint::new(rng.below(side as u64) as i32, rng.below(side as u64) as i32);
+            for height in b'0'..=b'9' {
+                map.set(&point, height as char);
+                let next = point.neighbours().collect::<Vec<GridPoint>>();
+                let next = *rng.pick(&next);
+                if next.is_in_bounds(map.get_bounds()) {
+                    point = next;
+                }
+            }
+        }
+        Generated::new(map.to_string())
+    }
+}
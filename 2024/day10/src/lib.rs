@@ -0,0 +1,95 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint, parse,
+    search::{self, Search},
+    Answer, Lint, Params, ParseError, Solution,
+};
+use grid::{Direction, Grid, GridPoint};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_grid = parse::char_grid(
+            parse::lines(input),
+            |ch| ch.is_ascii_digit(),
+            "a height digit",
+        )?;
+        Ok(Grid::new(input_grid))
+    }
+
+    // Calculate score - Sum of ( unique summits / trailhead )
+    fn part1(topographical_map: &Self::Input) -> Result<Answer> {
+        let mut total_score = 0;
+        for trailhead in topographical_map.find_all('0') {
+            total_score += find_all_summits(trailhead, topographical_map).len();
+        }
+        Ok(total_score.into())
+    }
+
+    // Calculate rating - Sum of ( unique paths / trailhead )
+    fn part2(topographical_map: &Self::Input) -> Result<Answer> {
+        let mut total_rating = 0;
+        for trailhead in topographical_map.find_all('0') {
+            // the same search as before, but counting every path to each summit
+            total_rating += find_rating(trailhead, topographical_map);
+        }
+        Ok(total_rating.into())
+    }
+}
+
+impl Lint for Day10 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::grid(
+            parse::lines(input),
+            |ch| ch.is_ascii_digit(),
+            "a height digit",
+        )
+    }
+}
+
+// Every step of a trail climbs by one, so every trail from a trailhead to a summit is a
+// shortest path and the search keeps all of them
+fn search_trails(start: GridPoint, topographical_map: &Grid) -> Search<GridPoint, usize> {
+    search::bfs(start, |&current_point: &GridPoint| {
+        let current_elevation = topographical_map.get(&current_point).map_or(0, elevation);
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| current_point + direction.step())
+            .filter(move |next_location| {
+                topographical_map.get(next_location).map(elevation) == Some(current_elevation + 1)
+            })
+    })
+}
+
+fn elevation(height: char) -> u32 {
+    height
+        .to_digit(10)
+        .expect("Could not parse elevation to digit")
+}
+
+fn summits<'a>(
+    trails: &'a Search<GridPoint, usize>,
+    topographical_map: &'a Grid,
+) -> impl Iterator<Item = &'a GridPoint> {
+    trails
+        .distances()
+        .keys()
+        .filter(|location| topographical_map.get(location) == Some('9'))
+}
+
+fn find_all_summits(start: GridPoint, topographical_map: &Grid) -> Vec<GridPoint> {
+    let trails = search_trails(start, topographical_map);
+    summits(&trails, topographical_map).copied().collect()
+}
+
+fn find_rating(start: GridPoint, topographical_map: &Grid) -> usize {
+    let trails = search_trails(start, topographical_map);
+    summits(&trails, topographical_map)
+        .map(|summit| trails.path_count(summit) as usize)
+        .sum()
+}
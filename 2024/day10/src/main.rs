@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day10::Day10;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day10>()?;
+
+    println!("Total Score: {}", Day10::part1(&input)?);
+    println!("Total Rating: {}", Day10::part2(&input)?);
+
+    Ok(())
+}
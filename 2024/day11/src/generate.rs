@@ -0,0 +1,13 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day11;
+
+// size is the number of stones
+impl Generate for Day11 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let stones = (0..size.max(1))
+            .map(|_| rng.range(0..=1_000_000).to_string())
+            .collect::<Vec<String>>();
+        Generated::new(format!("{}\n", stones.join(" ")))
+    }
+}
@@ -0,0 +1,213 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint,
+    memo::{self, Stats},
+    parse, Answer, Lint, Memo, Params, ParseError, Solution, Strategy,
+};
+
+use std::collections::HashMap;
+
+pub struct Day11;
+
+pub struct Stones {
+    values: Vec<u64>,
+    part1_blinks: usize,
+    part2_blinks: usize,
+    strategy: Blink,
+    memo_limit: Option<usize>,
+}
+
+// How the stones are kept between blinks
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Blink {
+    // Stones never affect each other, so count what each one becomes on its own,
+    // remembering the count for every value and blinks left
+    Memoized,
+    // One entry per stone value with how many stones have it
+    Counted,
+    // Every stone in order - the way I solved the 1st problem, far too slow for 75 blinks
+    EveryStone,
+}
+
+impl Strategy for Blink {
+    const ALL: &'static [Blink] = &[Blink::Memoized, Blink::Counted, Blink::EveryStone];
+
+    fn name(self) -> &'static str {
+        match self {
+            Blink::Memoized => "memoized",
+            Blink::Counted => "counted",
+            Blink::EveryStone => "every_stone",
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Stones;
+
+    fn parse(stones_string: &str) -> Result<Self::Input> {
+        Self::parse_with(stones_string, &Params::new())
+    }
+
+    fn parse_with(stones_string: &str, params: &Params) -> Result<Self::Input> {
+        let mut stone_values = Vec::new();
+        for line in parse::lines(stones_string) {
+            for stone in line.text.split_ascii_whitespace() {
+                stone_values.push(line.number::<u64>(stone)?);
+            }
+        }
+        Ok(Stones {
+            values: stone_values,
+            part1_blinks: params.get("part1_blinks", 25)?,
+            part2_blinks: params.get("part2_blinks", 75)?,
+            strategy: Blink::from_params(params)?,
+            memo_limit: memo::limit(params)?,
+        })
+    }
+
+    fn part1(stones: &Self::Input) -> Result<Answer> {
+        Ok(stones.count_after(stones.part1_blinks).into())
+    }
+
+    fn part2(stones: &Self::Input) -> Result<Answer> {
+        Ok(stones.count_after(stones.part2_blinks).into())
+    }
+}
+
+impl Lint for Day11 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::each_line(input, |line| {
+            line.text
+                .split_ascii_whitespace()
+                .try_for_each(|stone| line.number::<u64>(stone).map(|_| ()))
+        })
+    }
+}
+
+impl Stones {
+    fn count_after(&self, blinks: usize) -> usize {
+        self.count_with_stats(blinks).0
+    }
+
+    // The stone count after the given number of blinks, and how well the memo did when the
+    // strategy keeps one
+    pub fn count_with_stats(&self, blinks: usize) -> (usize, Option<Stats>) {
+        match self.strategy {
+            Blink::Memoized => {
+                let (count, stats) = self.count_memoized(blinks);
+                (count, Some(stats))
+            }
+            Blink::Counted => (count_stones(&self.values, blinks), None),
+            Blink::EveryStone => (count_every_stone(&self.values, blinks), None),
+        }
+    }
+
+    fn count_memoized(&self, blinks: usize) -> (usize, Stats) {
+        let mut memo = Memo::with_limit(self.memo_limit);
+        let count = self
+            .values
+            .iter()
+            .map(|&stone| count_from_stone(stone, blinks, &mut memo))
+            .sum();
+        (count, memo.stats())
+    }
+
+    pub fn part2_blinks(&self) -> usize {
+        self.part2_blinks
+    }
+}
+
+fn count_from_stone(stone: u64, blinks: usize, memo: &mut Memo<(u64, usize), usize>) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+    memo.get_or_insert_with((stone, blinks), |memo| {
+        update_stone_value(stone)
+            .into_iter()
+            .map(|new_val| count_from_stone(new_val, blinks - 1, memo))
+            .sum()
+    })
+}
+
+// Find stone count after the given number of blinks by updating every stone
+fn count_every_stone(stone_values: &[u64], blinks: usize) -> usize {
+    let mut stone_values = stone_values.to_vec();
+    let mut updated_values = Vec::new();
+    for _ in 0..blinks {
+        // Loop over each stone, update, and store the results in temp vec
+        for &stone in &stone_values {
+            let new_vals = update_stone_value(stone);
+            for val in new_vals {
+                updated_values.push(val);
+            }
+        }
+        // store updated values, clear temp vec to reuse its space next blink
+        std::mem::swap(&mut stone_values, &mut updated_values);
+        updated_values.clear();
+    }
+    stone_values.len()
+}
+
+// Find stone count after the given number of blinks
+fn count_stones(stone_values: &[u64], blinks: usize) -> usize {
+    // First it is clear that brute forcing to 75 blinks is not a viable option
+    // Then, notice that the order of the stones does not matter
+    // we actually only need the set of numbers and to keep track of how many there are of each value.
+    // So, we do the same as count_every_stone, but instead use a HashMap between the stones
+    // values and how many stone have that particular value instead of a Vec of stone values
+
+    // Initialize HashMap
+    let mut stone_hash: HashMap<u64, usize> = HashMap::new();
+    for &stone in stone_values {
+        *stone_hash.entry(stone).or_insert(0) += 1;
+    }
+
+    // Duplicate procedure from count_every_stone, but with a HashMap instead
+    let mut updated_hash: HashMap<u64, usize> = HashMap::new();
+    for _ in 0..blinks {
+        for (stone_val, stone_count) in stone_hash.iter() {
+            for new_val in update_stone_value(*stone_val) {
+                // update temp HashMap for each new val
+                *updated_hash.entry(new_val).or_insert(0) += stone_count;
+            }
+        }
+        // store updated values, reset temp hash to reuse its space next blink
+        std::mem::swap(&mut stone_hash, &mut updated_hash);
+        updated_hash.clear();
+    }
+    stone_hash.values().sum()
+}
+
+fn update_stone_value(current_value: u64) -> Vec<u64> {
+    if current_value == 0 {
+        return vec![1];
+    }
+    let digit_string = current_value.to_string();
+    let digit_count = digit_string.len();
+    if digit_count.is_multiple_of(2) {
+        let (left, right) = digit_string.split_at(digit_count / 2);
+        let left_digit = left.parse::<u64>().expect("Unable to parse left digit");
+        let right_digit = right.parse::<u64>().expect("Unable to parse right digit");
+        return vec![left_digit, right_digit];
+    }
+    vec![current_value * 2024]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{random::Rng, strategy, Generate};
+
+    #[test]
+    fn blink_strategies_agree() {
+        for seed in 0..30 {
+            // Few enough blinks for every_stone to keep up
+            let input = Day11::generate(&mut Rng::new(seed), 6)
+                .with_param("part1_blinks", 6)
+                .with_param("part2_blinks", 15);
+            strategy::check_agreement::<Day11, Blink>(&input.text, &input.params)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+}
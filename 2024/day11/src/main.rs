@@ -0,0 +1,20 @@
+use anyhow::Result;
+use aoc2024_day11::Day11;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day11>()?;
+
+    println!(
+        "Number of Stones after 25 blinks: {}",
+        Day11::part1(&input)?
+    );
+    // Part 2 by hand, so the memo stats come from the same count as the answer
+    let (count, stats) = input.count_with_stats(input.part2_blinks());
+    println!("Number of Stones after 75 blinks: {}", count);
+    if let Some(stats) = stats {
+        println!("Memo for {} blinks: {}", input.part2_blinks(), stats);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Grid, GridPoint};
+
+use crate::Day12;
+
+// size is the side of the square farm. Plots mostly copy the plant above or to the left,
+// which grows regions of some size instead of single scattered plots
+impl Generate for Day12 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(1);
+        let mut farm = Grid::filled((side, side), 'A');
+        for point in farm.points().collect::<Vec<GridPoint>>() {
+            let above = farm.get(&GridPoint::new(point.index1 - 1, point.index2));
+            let left = farm.get(&GridPoint::new(point.index1, point.index2 - 1));
+            let plant = match (rng.below(3), above, left) {
+                (0, Some(plant), _) | (1, _, Some(plant)) => plant,
+                _ => (b'A' + rng.below(6) as u8) as char,
+            };
+            farm.set(&point, plant);
+        }
+        Generated::new(farm.to_string())
+    }
+}
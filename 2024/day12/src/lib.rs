@@ -1,165 +1,179 @@
-use anyhow::Result;
+mod generate;
 
-use std::{
-    char,
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::Add,
+use anyhow::{Error, Result};
+use common::{
+    lint, parse, repl, repl::Command, Answer, Inspect, Lint, Params, ParseError, Picture, Solution,
+    Strategy,
 };
+use grid::{Direction, Grid, GridPoint};
 
-fn main() -> Result<()> {
-    // Read Input
-    // let input_file = File::open("puzzle_input_example.txt")?;
-    let input_file = File::open("puzzle_input.txt")?;
-    let input_lines = BufReader::new(input_file).lines();
+pub struct Day12;
 
-    // Load to Grid
-    let mut input_grid: Vec<Vec<char>> = Vec::new();
-    for line in input_lines {
-        match line {
-            Ok(line_string) => {
-                input_grid.push(line_string.chars().collect());
-            }
-            Err(e) => {
-                return Err(anyhow::Error::new(e));
-            }
-        }
-    }
-    let farmland = Grid::new(input_grid);
+pub struct Farm {
+    grid: Grid,
+    strategy: Extent,
+}
 
-    // Find Regions
-    let t0 = std::time::Instant::now();
-    let (index1_max, index2_max) = farmland.get_bounds();
-    let mut regions: Vec<Region> = Vec::new();
-    for i in 0..index1_max {
-        for j in 0..index2_max {
-            let next_gp = GridPoint::new(i as i32, j as i32);
-            if !regions.iter().any(|reg| reg.contains(&next_gp)) {
-                if let Some(crop_type) = farmland.get(&next_gp) {
-                    let mut new_region = Region::new(next_gp, crop_type);
-                    new_region.get_extent(&farmland);
-                    regions.push(new_region);
-                }
-            }
+// How a region grows out from its first point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extent {
+    // get_extent2(): walk the points in order, adding neighbours to the end as they are found
+    Queue,
+    // get_extent(): rescan every point until a pass adds nothing new
+    Rescan,
+}
+
+impl Strategy for Extent {
+    const ALL: &'static [Extent] = &[Extent::Queue, Extent::Rescan];
+
+    fn name(self) -> &'static str {
+        match self {
+            Extent::Queue => "queue",
+            Extent::Rescan => "rescan",
         }
     }
-    println!(
-        "get_extent(): {}ms",
-        (std::time::Instant::now() - t0).as_millis()
-    );
+}
 
-    let t1 = std::time::Instant::now();
-    let (index1_max, index2_max) = farmland.get_bounds();
-    let mut regions: Vec<Region> = Vec::new();
-    for i in 0..index1_max {
-        for j in 0..index2_max {
-            let next_gp = GridPoint::new(i as i32, j as i32);
-            if !regions.iter().any(|reg| reg.contains(&next_gp)) {
-                if let Some(crop_type) = farmland.get(&next_gp) {
-                    let mut new_region = Region::new(next_gp, crop_type);
-                    new_region.get_extent2(&farmland);
-                    regions.push(new_region);
-                }
-            }
+impl Extent {
+    fn method(self) -> fn(&mut Region, &Grid) {
+        match self {
+            Extent::Queue => Region::get_extent2,
+            Extent::Rescan => Region::get_extent,
         }
     }
-    println!(
-        "get_extent2(): {}ms",
-        (std::time::Instant::now() - t1).as_millis()
-    );
+}
 
-    // Calcuate the total cost: (Area * perimeter)
-    let mut total_cost = 0;
-    for region in regions.clone() {
-        total_cost += region.get_area() * region.get_perimeter();
-    }
-    println!("Total Cost: {}", total_cost);
+impl Solution for Day12 {
+    type Input = Farm;
 
-    // Calculate the bulk discounted cost: (Area * sides)
-    let mut discounted_cost = 0;
-    for region in regions {
-        discounted_cost += region.get_area() * region.count_sides();
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
     }
-    println!("Discounted Cost: {}", discounted_cost);
-
-    Ok(())
-}
 
-#[derive(Clone)]
-struct Grid {
-    grid: Vec<Vec<char>>,
-}
-
-impl Grid {
-    fn new(grid: Vec<Vec<char>>) -> Grid {
-        Grid { grid }
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let input_grid = parse::char_grid(
+            parse::lines(input),
+            |ch| ch.is_ascii_uppercase(),
+            "a plant type letter",
+        )?;
+        Ok(Farm {
+            grid: Grid::new(input_grid),
+            strategy: Extent::from_params(params)?,
+        })
     }
 
-    fn get_bounds(&self) -> (usize, usize) {
-        (self.grid.len(), self.grid[0].len())
+    // Calcuate the total cost: (Area * perimeter)
+    fn part1(farm: &Self::Input) -> Result<Answer> {
+        let mut total_cost = 0;
+        for region in find_regions(&farm.grid, farm.strategy.method()) {
+            total_cost += region.get_area() * region.get_perimeter();
+        }
+        Ok(total_cost.into())
     }
 
-    fn get(&self, location: &GridPoint) -> Option<char> {
-        let bounds = GridPoint::new(self.grid.len() as i32, self.grid[0].len() as i32);
-        if location.index1 >= bounds.index1
-            || location.index2 >= bounds.index2
-            || location.index1 < 0
-            || location.index2 < 0
-        {
-            // Out of Bounds
-            None
-        } else {
-            Some(self.grid[location.index1 as usize][location.index2 as usize])
+    // Calculate the bulk discounted cost: (Area * sides)
+    fn part2(farm: &Self::Input) -> Result<Answer> {
+        let mut discounted_cost = 0;
+        for region in find_regions(&farm.grid, farm.strategy.method()) {
+            discounted_cost += region.get_area() * region.count_sides();
         }
+        Ok(discounted_cost.into())
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
-struct GridPoint {
-    index1: i32,
-    index2: i32,
+impl Lint for Day12 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::grid(
+            parse::lines(input),
+            |ch| ch.is_ascii_uppercase(),
+            "a plant type letter",
+        )
+    }
 }
 
-impl GridPoint {
-    fn new(index1: i32, index2: i32) -> GridPoint {
-        GridPoint { index1, index2 }
+// Neighbouring regions never share a letter, so colouring by letter shows every region
+impl Picture for Day12 {
+    fn picture(farm: &Self::Input) -> Result<String> {
+        Ok(farm.grid.to_string())
     }
 }
 
-impl Add<&GridPoint> for &GridPoint {
-    type Output = GridPoint;
-    fn add(self, rhs: &GridPoint) -> Self::Output {
-        GridPoint {
-            index1: self.index1 + rhs.index1,
-            index2: self.index2 + rhs.index2,
-        }
+// Nothing changes here, the commands only look at the farm
+impl Inspect for Day12 {
+    type State = ();
+
+    fn state(_: &Self::Input) {}
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "show",
+                usage: "show",
+                help: "draw the farm",
+                run: |farm, _, _| Ok(farm.grid.to_string()),
+            },
+            Command {
+                name: "cell",
+                usage: "cell ROW COL",
+                help: "the plant type on a plot",
+                run: |farm, _, args| {
+                    let point = point_arg(farm, args)?;
+                    Ok(farm.grid.get(&point).map(String::from).unwrap_or_default())
+                },
+            },
+            Command {
+                name: "region",
+                usage: "region ROW COL",
+                help: "area, perimeter and sides of the region holding a plot, drawn alone",
+                run: |farm, _, args| {
+                    let start = point_arg(farm, args)?;
+                    let mut region = Region::new(start, farm.grid.get(&start).unwrap_or('.'));
+                    farm.strategy.method()(&mut region, &farm.grid);
+                    let mut alone = Grid::filled(farm.grid.get_bounds(), '.');
+                    for point in &region.points {
+                        alone.set(point, region.crop_type);
+                    }
+                    Ok(format!(
+                        "{}{}: area {}, perimeter {}, sides {}",
+                        alone,
+                        region.crop_type,
+                        region.get_area(),
+                        region.get_perimeter(),
+                        region.count_sides()
+                    ))
+                },
+            },
+        ]
     }
 }
 
-#[derive(Clone, Debug, Copy, PartialEq)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
+fn point_arg(farm: &Farm, args: &[&str]) -> Result<GridPoint> {
+    let point = GridPoint::new(
+        repl::arg(args, 0, "a row", None)?,
+        repl::arg(args, 1, "a column", None)?,
+    );
+    if !point.is_in_bounds(farm.grid.get_bounds()) {
+        return Err(Error::msg("Outside the farm"));
+    }
+    Ok(point)
 }
 
-impl Direction {
-    const ALL: [Direction; 4] = [
-        Direction::Up,
-        Direction::Right,
-        Direction::Down,
-        Direction::Left,
-    ];
-
-    fn step(&self) -> GridPoint {
-        match self {
-            Direction::Up => GridPoint::new(-1, 0),
-            Direction::Right => GridPoint::new(0, 1),
-            Direction::Down => GridPoint::new(1, 0),
-            Direction::Left => GridPoint::new(0, -1),
+fn find_regions(farmland: &Grid, get_extent: fn(&mut Region, &Grid)) -> Vec<Region> {
+    let (index1_max, index2_max) = farmland.get_bounds();
+    let mut regions: Vec<Region> = Vec::new();
+    for i in 0..index1_max {
+        for j in 0..index2_max {
+            let next_gp = GridPoint::new(i as i32, j as i32);
+            if !regions.iter().any(|reg| reg.contains(&next_gp)) {
+                if let Some(crop_type) = farmland.get(&next_gp) {
+                    let mut new_region = Region::new(next_gp, crop_type);
+                    get_extent(&mut new_region, farmland);
+                    regions.push(new_region);
+                }
+            }
         }
     }
+    regions
 }
 
 #[derive(Debug, Clone)]
@@ -189,7 +203,7 @@ impl Region {
             added_new_points = false;
             for point in &self.points {
                 for direction in Direction::ALL {
-                    let next_point = point + &direction.step();
+                    let next_point = *point + direction.step();
                     if !self.points.contains(&next_point)
                         && !new_points.contains(&next_point)
                         && Some(self.crop_type) == farmland.get(&next_point)
@@ -206,9 +220,9 @@ impl Region {
     fn get_extent2(&mut self, farmland: &Grid) {
         // This finds all connected points and adds them to self.points
         let mut current_index = 0;
-        while let Some(point) = self.points.clone().get(current_index) {
+        while let Some(&point) = self.points.get(current_index) {
             for direction in Direction::ALL {
-                let next_point = point + &direction.step();
+                let next_point = point + direction.step();
                 if !self.points.contains(&next_point)
                     && Some(self.crop_type) == farmland.get(&next_point)
                 {
@@ -227,7 +241,7 @@ impl Region {
         let mut perimeter = 0;
         for point in &self.points {
             for direction in Direction::ALL {
-                let neighbor_point = point + &direction.step();
+                let neighbor_point = *point + direction.step();
                 if !self.points.contains(&neighbor_point) {
                     perimeter += 1
                 }
@@ -244,7 +258,7 @@ impl Region {
                 .points
                 .clone()
                 .into_iter()
-                .filter(|p| !self.points.contains(&(p + &direction.step())))
+                .filter(|p| !self.points.contains(&(*p + direction.step())))
                 .collect::<Vec<GridPoint>>();
             match direction {
                 Direction::Up | Direction::Down => {
@@ -321,7 +335,7 @@ impl Region {
         // at least 1 step
         let mut gaps = 0;
         let mut prev_was_gap = false;
-        let mut next_point = start + &iter_dir.step();
+        let mut next_point = *start + iter_dir.step();
         while &next_point != stop {
             if !point_list.contains(&next_point) {
                 if !prev_was_gap {
@@ -331,8 +345,23 @@ impl Region {
             } else {
                 prev_was_gap = false;
             }
-            next_point = &next_point + &iter_dir.step();
+            next_point = next_point + iter_dir.step();
         }
         gaps
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{random::Rng, strategy, Generate};
+
+    #[test]
+    fn extent_strategies_agree() {
+        for seed in 0..30 {
+            let input = Day12::generate(&mut Rng::new(seed), 12);
+            strategy::check_agreement::<Day12, Extent>(&input.text, &input.params)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day12::Day12;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let farmland = input::parse_from_args::<Day12>()?;
+
+    println!("Total Cost: {}", Day12::part1(&farmland)?);
+    println!("Discounted Cost: {}", Day12::part2(&farmland)?);
+
+    Ok(())
+}
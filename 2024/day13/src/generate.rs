@@ -0,0 +1,35 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day13;
+
+// size is the number of claw machines. Most prizes can be reached, some only with more
+// than MAX_PRESSES presses and some not at all
+impl Generate for Day13 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut machines = Vec::new();
+        while machines.len() < size.max(1) {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            // Now and then the buttons move along the same line
+            let b = if rng.one_in(4) {
+                let scale = rng.range(1..=3);
+                (a.0 * scale, a.1 * scale)
+            } else {
+                b
+            };
+            let (a_presses, b_presses) = (rng.range(0..=120), rng.range(0..=120));
+            let mut prize = (
+                a.0 * a_presses + b.0 * b_presses,
+                a.1 * a_presses + b.1 * b_presses,
+            );
+            if rng.one_in(4) {
+                prize.0 += 1;
+            }
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        Generated::new(machines.join("\n"))
+    }
+}
@@ -0,0 +1,349 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint,
+    parse::{self, Line},
+    Answer, Lint, Params, ParseError, Solution, Strategy,
+};
+use regex::Regex;
+
+use std::ops::{Add, Mul};
+
+// Added to both coordinates of every prize in part 2
+const CONVERSION_OFFSET: i64 = 10000000000000;
+// Part 1 says no button is pressed more than this many times
+const MAX_PRESSES: i64 = 100;
+
+pub struct Day13;
+
+// How part 1 finds the cheapest presses, part 2 can only use the closed form
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Presses {
+    // Try every combination up to the press limit
+    BruteForce,
+    // Solve the two linear equations directly
+    ClosedForm,
+}
+
+impl Strategy for Presses {
+    const ALL: &'static [Presses] = &[Presses::BruteForce, Presses::ClosedForm];
+
+    fn name(self) -> &'static str {
+        match self {
+            Presses::BruteForce => "brute_force",
+            Presses::ClosedForm => "closed_form",
+        }
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Arcade;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let mut claw_machines = Vec::new();
+        for claw_machine_lines in parse::sections(input) {
+            claw_machines.push(ClawMachine::from_lines(&claw_machine_lines)?);
+        }
+        let offset = params.get("conversion_offset", CONVERSION_OFFSET)?;
+        Ok(Arcade {
+            claw_machines,
+            conversion_offset: Position::new(offset, offset),
+            strategy: Presses::from_params(params)?,
+        })
+    }
+
+    fn part1(arcade: &Self::Input) -> Result<Answer> {
+        let total_min_cost =
+            arcade
+                .claw_machines
+                .iter()
+                .fold(0, |acc, claw| match arcade.strategy {
+                    Presses::BruteForce => acc + claw.find_min_cost_solution(),
+                    Presses::ClosedForm => {
+                        acc + claw.find_min_cost_with_offset(Position::new(0, 0), Some(MAX_PRESSES))
+                    }
+                });
+        Ok(total_min_cost.into())
+    }
+
+    fn part2(arcade: &Self::Input) -> Result<Answer> {
+        let total_corrected_min_cost = arcade.claw_machines.iter().fold(0, |acc, claw| {
+            acc + claw.find_min_cost_with_offset(arcade.conversion_offset, None)
+        });
+        Ok(total_corrected_min_cost.into())
+    }
+}
+
+// Three lines a machine, with exactly one blank line between machines
+impl Lint for Day13 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let (machines, mut problems) = lint::sections(input);
+        for machine in machines {
+            for (i, prefix) in ["Button A: ", "Button B: ", "Prize: "].iter().enumerate() {
+                let problem = match machine.get(i) {
+                    Some(line) => parse_digits(line, prefix).err(),
+                    None => {
+                        let last = machine.last().expect("sections are never empty");
+                        Some(ParseError::new(
+                            last.number + 1,
+                            1,
+                            format!("{:?}", prefix),
+                            "the end of the machine",
+                        ))
+                    }
+                };
+                problems.extend(problem);
+            }
+            for line in machine.iter().skip(3) {
+                problems.push(line.error(line.text, "a blank line after the prize"));
+            }
+        }
+        problems
+    }
+}
+
+pub struct Arcade {
+    claw_machines: Vec<ClawMachine>,
+    conversion_offset: Position,
+    strategy: Presses,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64) -> Position {
+        Position { x, y }
+    }
+}
+
+impl Add<Position> for Position {
+    type Output = Position;
+    fn add(self, rhs: Position) -> Position {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Mul<Position> for i64 {
+    type Output = Position;
+    fn mul(self, rhs: Position) -> Position {
+        Position {
+            x: self * rhs.x,
+            y: self * rhs.y,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ClawMachine {
+    button_a: Position,
+    button_b: Position,
+    target: Position,
+}
+
+impl ClawMachine {
+    // One section of the input, missing lines are reported just after its last one
+    fn from_lines(lines: &[Line]) -> Result<ClawMachine, ParseError> {
+        let line = |i: usize, prefix: &str| match lines.get(i) {
+            Some(line) => parse_digits(line, prefix),
+            None => {
+                let last = lines.last().expect("sections are never empty");
+                Err(ParseError::new(
+                    last.number + 1,
+                    1,
+                    format!("{:?}", prefix),
+                    "the end of the machine",
+                ))
+            }
+        };
+        let button_a = line(0, "Button A: ")?;
+        let button_b = line(1, "Button B: ")?;
+        let target = line(2, "Prize: ")?;
+        if let Some(extra) = lines.get(3) {
+            return Err(extra.error(extra.text, "a blank line after the prize"));
+        }
+
+        Ok(ClawMachine {
+            button_a,
+            button_b,
+            target,
+        })
+    }
+
+    fn find_min_cost_solution(&self) -> i64 {
+        let mut min_cost = i64::MAX;
+        // with a max press count of 100 we can easily just try all
+        // 10,000 combos per claw machine and find the cheapest one that works
+        for a_presses in 0..=MAX_PRESSES {
+            for b_presses in 0..=MAX_PRESSES {
+                let current_cost = a_presses * 3 + b_presses;
+                if current_cost < min_cost
+                    && a_presses * self.button_a + b_presses * self.button_b == self.target
+                {
+                    min_cost = current_cost
+                }
+            }
+        }
+        if min_cost != i64::MAX {
+            min_cost
+        } else {
+            0
+        }
+    }
+
+    fn find_min_cost_with_offset(
+        &self,
+        conversion_offset: Position,
+        max_presses: Option<i64>,
+    ) -> i64 {
+        // it is clear that we cannot brute force try every combo to calculate the solution
+        // however we reframe the problem with Linear Algebra
+        //                M                 *       x         =       b
+        // _______________________________     _____________     ____________
+        // | button_a_x   ,   button_b_x |  *  | a_presses |  =  | target_x |
+        // | button_a_y   ,   button_b_y |     | b_presses |     | target_y |
+        // -------------------------------     -------------     ------------
+        //  This implies then that we may find x by inverting M as
+        //      x         =                    M^-1                     *       b
+        // _____________       1     _________________________________     ____________
+        // | a_presses |  =   ---  * |  button_b_y   ,   -button_b_x |  *  | target_x |
+        // | b_presses |     det(M)  | -button_a_y   ,    button_a_x |     | target_y |
+        // -------------             ---------------------------------     ------------
+
+        let corrected_target = self.target + conversion_offset;
+        let det_m = self.button_a.x * self.button_b.y - self.button_b.x * self.button_a.y;
+        if det_m == 0 {
+            // The buttons move along the same line, so M has no inverse and one equation is
+            // left, along x unless both buttons only move in y
+            let (a, b) = (self.button_a, self.button_b);
+            let axis = if a.x != 0 || b.x != 0 {
+                cheapest_on_line(a.x, b.x, corrected_target.x, max_presses)
+            } else {
+                cheapest_on_line(a.y, b.y, corrected_target.y, max_presses)
+            };
+            return match axis {
+                Some((a_presses, b_presses))
+                    if a_presses * a + b_presses * b == corrected_target =>
+                {
+                    3 * a_presses + b_presses
+                }
+                _ => 0,
+            };
+        }
+        let a_presses =
+            (self.button_b.y * corrected_target.x - self.button_b.x * corrected_target.y) / det_m;
+        let b_presses =
+            (self.button_a.x * corrected_target.y - self.button_a.y * corrected_target.x) / det_m;
+
+        // this is to check if it is actually a solution in the Natural Numbers
+        let test_position = a_presses * self.button_a + b_presses * self.button_b;
+        let allowed = |presses: i64| presses >= 0 && max_presses.is_none_or(|max| presses <= max);
+        if test_position == corrected_target && allowed(a_presses) && allowed(b_presses) {
+            3 * a_presses + b_presses
+        } else {
+            0
+        }
+    }
+}
+
+// The cheapest presses of two buttons that move a and b along one axis to reach target.
+// Every solution is (a0 + t * b / g, b0 - t * a / g) for a whole t, and the cost changes by
+// the same amount with each step of t, so the cheapest is at one end of the t that keep
+// both press counts between 0 and the limit
+fn cheapest_on_line(a: i64, b: i64, target: i64, max_presses: Option<i64>) -> Option<(i64, i64)> {
+    let (a, b, target) = (i128::from(a), i128::from(b), i128::from(target));
+    let max = i128::from(max_presses.unwrap_or(i64::MAX));
+    let (g, x, y) = extended_gcd(a, b);
+    if g == 0 || target % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x * (target / g), y * (target / g));
+    let (step_a, step_b) = (b / g, a / g);
+
+    let (mut low, mut high) = (i128::MIN, i128::MAX);
+    for (start, step) in [(a0, step_a), (b0, -step_b)] {
+        match step.signum() {
+            0 if !(0..=max).contains(&start) => return None,
+            0 => {}
+            1 => {
+                low = low.max(ceil_div(-start, step));
+                high = high.min(floor_div(max - start, step));
+            }
+            _ => {
+                low = low.max(ceil_div(max - start, step));
+                high = high.min(floor_div(-start, step));
+            }
+        }
+    }
+    if low > high {
+        return None;
+    }
+    let presses = |t: i128| (a0 + t * step_a, b0 - t * step_b);
+    let cost = |(a, b): (i128, i128)| 3 * a + b;
+    let best = if cost(presses(low)) <= cost(presses(high)) {
+        presses(low)
+    } else {
+        presses(high)
+    };
+    Some((best.0 as i64, best.1 as i64))
+}
+
+// gcd(a, b) with x and y such that a * x + b * y == gcd(a, b)
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    if d < 0 {
+        return floor_div(-n, -d);
+    }
+    n.div_euclid(d)
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    -floor_div(-n, d)
+}
+
+fn parse_digits(line: &Line, prefix: &str) -> Result<Position, ParseError> {
+    let button_string = line.strip_prefix(line.text, prefix)?;
+    let digit_regex = Regex::new(r"\d+").unwrap();
+    let mut digits = digit_regex
+        .find_iter(button_string)
+        .map(|s| line.number::<i64>(s.as_str()));
+    let mut next_digit = || {
+        digits
+            .next()
+            .unwrap_or_else(|| Err(line.error(line.end(), "a number")))
+    };
+    Ok(Position::new(next_digit()?, next_digit()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{random::Rng, strategy, Generate};
+
+    #[test]
+    fn press_strategies_agree() {
+        for seed in 0..30 {
+            let input = Day13::generate(&mut Rng::new(seed), 8);
+            strategy::check_agreement::<Day13, Presses>(&input.text, &input.params)
+                .unwrap_or_else(|e| panic!("seed {}: {}", seed, e));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day13::Day13;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day13>()?;
+
+    println!("Total Min Cost: {}", Day13::part1(&input)?);
+    println!("Total Min Cost: {}", Day13::part2(&input)?);
+
+    Ok(())
+}
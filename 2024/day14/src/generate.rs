@@ -0,0 +1,42 @@
+use common::{random::Rng, Generate, Generated};
+
+use std::collections::HashSet;
+
+use crate::Day14;
+
+// size is the number of robots, in a room with about twenty tiles for each of them. The
+// robots are placed at distinct tiles at some second and then run backwards from there, so
+// part 2 always has a second to find
+impl Generate for Day14 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let robots = size.max(1);
+        // Both sides odd, so the middle row and column split the room into quadrants
+        let side = ((robots * 20) as f64).sqrt() as i64;
+        let (width, height) = (side.max(11) | 1, (side + 2).max(7) | 1);
+        let seconds = rng.range(0..=width * height - 1);
+
+        let mut taken = HashSet::new();
+        let mut text = String::new();
+        while taken.len() < robots {
+            let end = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+            if !taken.insert(end) {
+                continue;
+            }
+            let velocity = (
+                rng.range(-width + 1..=width - 1),
+                rng.range(-height + 1..=height - 1),
+            );
+            let start = (
+                (end.0 - seconds * velocity.0).rem_euclid(width),
+                (end.1 - seconds * velocity.1).rem_euclid(height),
+            );
+            text += &format!(
+                "p={},{} v={},{}\n",
+                start.0, start.1, velocity.0, velocity.1
+            );
+        }
+        Generated::new(text)
+            .with_param("room_width", width)
+            .with_param("room_height", height)
+    }
+}
@@ -0,0 +1,334 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint::Positions,
+    parse::{self, Line},
+    repl,
+    repl::Command,
+    Animate, Answer, Frame, Frames, Inspect, Lint, Params, ParseError, Picture, Solution,
+};
+use std::{collections::HashSet, ops::Add};
+
+// given written in problem for actual input, the example uses room_width=11 and room_height=7
+const ROOM: Vector2D = Vector2D::new(101, 103);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Lobby;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let room = Vector2D::new(
+            params.get("room_width", ROOM.x)?,
+            params.get("room_height", ROOM.y)?,
+        );
+        if room.x <= 0 || room.y <= 0 {
+            return Err(anyhow::Error::msg(format!(
+                "The room is {} by {}, both sides must be at least 1",
+                room.x, room.y
+            )));
+        }
+        Ok(Lobby {
+            robots: parse_robots(input)?,
+            room,
+        })
+    }
+
+    fn part1(lobby: &Self::Input) -> Result<Answer> {
+        let mut robots = lobby.robots.clone();
+        let room = lobby.room;
+        for _seconds in 0..100 {
+            for robot in &mut robots {
+                robot.step(&room)
+            }
+        }
+
+        let safety_factor: usize = quadrant_counts(&robots, &room).iter().product();
+        Ok(safety_factor.into())
+    }
+
+    // The seconds are what the puzzle asks for, the frame shows they are right
+    fn part2(lobby: &Self::Input) -> Result<Answer> {
+        let (seconds, robots) = find_tree(lobby)?;
+        let picture = room_picture(&robots, &lobby.room);
+        Ok(Answer::grid(seconds as i64, &picture))
+    }
+}
+
+// Robots can share a position, but all of them start inside the room
+impl Lint for Day14 {
+    fn lint(input: &str, params: &Params) -> Vec<ParseError> {
+        let width = params.get("room_width", ROOM.x).unwrap_or(ROOM.x);
+        let height = params.get("room_height", ROOM.y).unwrap_or(ROOM.y);
+        let mut positions = Positions::new(width.into(), height.into(), false);
+        let mut problems = Vec::new();
+        for line in parse::lines(input) {
+            match parse_robot(&line) {
+                Ok(robot) => {
+                    let token = line.text.split_whitespace().next().unwrap_or(line.text);
+                    let (x, y) = (robot.pos.x.into(), robot.pos.y.into());
+                    problems.extend(positions.check(&line, token, x, y));
+                }
+                Err(problem) => problems.push(problem),
+            }
+        }
+        problems
+    }
+}
+
+impl Animate for Day14 {
+    // One frame per second until the positions start repeating
+    fn frames(lobby: &Self::Input) -> Frames<'_> {
+        let mut robots = lobby.robots.clone();
+        let room = lobby.room;
+        Box::new((0..(room.x * room.y) as usize).map(move |seconds| {
+            if seconds > 0 {
+                for robot in &mut robots {
+                    robot.step(&room);
+                }
+            }
+            Frame::new(
+                room_picture(&robots, &room),
+                format!("seconds: {}", seconds),
+            )
+        }))
+    }
+}
+
+// The room when the tree shows up
+impl Picture for Day14 {
+    fn picture(lobby: &Self::Input) -> Result<String> {
+        let (_, robots) = find_tree(lobby)?;
+        Ok(room_picture(&robots, &lobby.room))
+    }
+}
+
+impl Inspect for Day14 {
+    type State = Clock;
+
+    fn state(lobby: &Self::Input) -> Clock {
+        Clock {
+            seconds: 0,
+            robots: lobby.robots.clone(),
+        }
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "step",
+                usage: "step [N]",
+                help: "let N seconds pass, one by default",
+                run: |lobby, clock, args| {
+                    let seconds: usize = repl::arg(args, 0, "a number of seconds", Some(1))?;
+                    for _ in 0..seconds {
+                        for robot in &mut clock.robots {
+                            robot.step(&lobby.room);
+                        }
+                    }
+                    clock.seconds += seconds;
+                    Ok(format!("seconds: {}", clock.seconds))
+                },
+            },
+            Command {
+                name: "show",
+                usage: "show",
+                help: "draw the room",
+                run: |lobby, clock, _| {
+                    let picture = room_picture(&clock.robots, &lobby.room);
+                    Ok(format!("{}seconds: {}", picture, clock.seconds))
+                },
+            },
+            Command {
+                name: "seconds",
+                usage: "seconds",
+                help: "how many seconds have passed",
+                run: |_, clock, _| Ok(clock.seconds.to_string()),
+            },
+            Command {
+                name: "quadrants",
+                usage: "quadrants",
+                help: "robots in each quadrant and their safety factor",
+                run: |lobby, clock, _| {
+                    let counts = quadrant_counts(&clock.robots, &lobby.room);
+                    Ok(format!(
+                        "Q1 {}, Q2 {}, Q3 {}, Q4 {}, safety factor {}",
+                        counts[0],
+                        counts[1],
+                        counts[2],
+                        counts[3],
+                        counts.iter().product::<usize>()
+                    ))
+                },
+            },
+        ]
+    }
+}
+
+// The robots some number of seconds in
+pub struct Clock {
+    seconds: usize,
+    robots: Vec<Robot>,
+}
+
+pub struct Lobby {
+    robots: Vec<Robot>,
+    room: Vector2D,
+}
+
+// The robots only form the tree when none of them share a position.
+// Their positions repeat every room.x * room.y seconds, so stop looking after that
+fn find_tree(lobby: &Lobby) -> Result<(usize, Vec<Robot>)> {
+    let mut robots = lobby.robots.clone();
+    let room = lobby.room;
+    for seconds in 0..(room.x * room.y) as usize {
+        let positions = robots.iter().map(|r| r.pos).collect::<HashSet<_>>();
+        if positions.len() == robots.len() {
+            return Ok((seconds, robots));
+        }
+        for robot in &mut robots {
+            robot.step(&room);
+        }
+    }
+    Err(anyhow::Error::msg("Robots never form a Christmas tree"))
+}
+
+// Robots in Q1 to Q4, those on the middle lines count for none of them
+fn quadrant_counts(robots: &[Robot], room: &Vector2D) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for robot in robots {
+        match robot.get_quadrant_loc(room) {
+            Quadrant::Q1 => counts[0] += 1,
+            Quadrant::Q2 => counts[1] += 1,
+            Quadrant::Q3 => counts[2] += 1,
+            Quadrant::Q4 => counts[3] += 1,
+            Quadrant::None => {}
+        }
+    }
+    counts
+}
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse::lines(input).map(|line| parse_robot(&line)).collect()
+}
+
+fn parse_robot(line: &Line) -> Result<Robot, ParseError> {
+    let (pos_string, vel_string) = line.split_once(line.text, " ")?;
+    let position = parse_vector(line, pos_string, "p=")?;
+    let velocity = parse_vector(line, vel_string, "v=")?;
+    Ok(Robot::new(position, velocity))
+}
+
+// Reads the x,y pair of a position (p=) or velocity (v=)
+fn parse_vector(line: &Line, vector_str: &str, prefix: &str) -> Result<Vector2D, ParseError> {
+    let cleaned = line.strip_prefix(vector_str, prefix)?;
+    let (x_str, y_str) = line.split_once(cleaned, ",")?;
+    let x = line.number::<i32>(x_str)?;
+    let y = line.number::<i32>(y_str)?;
+    Ok(Vector2D { x, y })
+}
+
+fn room_picture(robots: &[Robot], room_size: &Vector2D) -> String {
+    let positions = robots.iter().map(|r| r.pos).collect::<HashSet<_>>();
+    let mut picture = String::new();
+    for y in 0..room_size.y {
+        for x in 0..room_size.x {
+            if positions.contains(&Vector2D::new(x, y)) {
+                picture.push('X');
+            } else {
+                picture.push('.');
+            }
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+#[derive(Debug, PartialEq)]
+enum Quadrant {
+    Q1,
+    Q2,
+    Q3,
+    Q4,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Vector2D {
+    x: i32,
+    y: i32,
+}
+
+impl Vector2D {
+    const fn new(x: i32, y: i32) -> Vector2D {
+        Vector2D { x, y }
+    }
+}
+
+impl Add<Vector2D> for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, rhs: Vector2D) -> Self::Output {
+        Vector2D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Robot {
+    pos: Vector2D,
+    vel: Vector2D,
+}
+
+impl Robot {
+    fn new(pos: Vector2D, vel: Vector2D) -> Robot {
+        Robot { pos, vel }
+    }
+
+    fn step(&mut self, room_size: &Vector2D) {
+        let next_pos = self.pos + self.vel;
+        self.pos.x = next_pos.x.rem_euclid(room_size.x);
+        self.pos.y = next_pos.y.rem_euclid(room_size.y);
+    }
+
+    fn get_quadrant_loc(&self, room_size: &Vector2D) -> Quadrant {
+        let x_split = room_size.x / 2;
+        let y_split = room_size.y / 2;
+        if self.pos.x > x_split && self.pos.y < y_split {
+            Quadrant::Q1
+        } else if self.pos.x < x_split && self.pos.y < y_split {
+            Quadrant::Q2
+        } else if self.pos.x < x_split && self.pos.y > y_split {
+            Quadrant::Q3
+        } else if self.pos.x > x_split && self.pos.y > y_split {
+            Quadrant::Q4
+        } else {
+            Quadrant::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_room_must_have_some_size() {
+        for (width, height) in [("0", "7"), ("11", "-7")] {
+            let mut params = Params::new();
+            params.set("room_width", width);
+            params.set("room_height", height);
+            let Err(error) = Day14::parse_with("p=0,4 v=3,-3\n", &params) else {
+                panic!("a {} by {} room parsed", width, height);
+            };
+            assert!(error.to_string().starts_with("The room is"), "{}", error);
+        }
+    }
+}
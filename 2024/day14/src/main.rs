@@ -0,0 +1,16 @@
+use anyhow::Result;
+use aoc2024_day14::Day14;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let lobby = input::parse_from_args::<Day14>()?;
+    let tree = Day14::part2(&lobby)?;
+
+    println!("Safety Factor: {}", Day14::part1(&lobby)?);
+    println!("Christmas Tree after: {} seconds", tree);
+    for row in tree.rows().unwrap_or_default() {
+        println!("{}", row);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Grid, GridPoint};
+
+use crate::Day15;
+
+const MOVES: [char; 4] = ['<', '>', '^', 'v'];
+
+// size is the side of the square warehouse, walled all round, with twice as many moves as
+// it has tiles
+impl Generate for Day15 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(3);
+        let mut warehouse = Grid::filled((side, side), '#');
+        let mut floor = Vec::new();
+        for i in 1..side - 1 {
+            for j in 1..side - 1 {
+                let point = GridPoint::new(i as i32, j as i32);
+                let tile = match rng.below(12) {
+                    0 => '#',
+                    1..=3 => 'O',
+                    _ => '.',
+                };
+                warehouse.set(&point, tile);
+                floor.push(point);
+            }
+        }
+        warehouse.set(rng.pick(&floor), '@');
+
+        let mut text = warehouse.to_string();
+        text.push('\n');
+        for _ in 0..(2 * side * side).div_ceil(70) {
+            let moves = (0..70).map(|_| *rng.pick(&MOVES)).collect::<String>();
+            text += &moves;
+            text.push('\n');
+        }
+        Generated::new(text)
+    }
+}
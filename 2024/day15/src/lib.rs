@@ -0,0 +1,411 @@
+mod generate;
+
+use anyhow::{Error, Result};
+use common::{
+    lint, parse, repl, repl::Command, Animate, Answer, Frame, Frames, Inspect, Lint, Params,
+    ParseError, Solution,
+};
+use grid::{Direction, Grid, GridPoint};
+
+pub struct Day15;
+
+pub struct Warehouse {
+    grid: Grid,
+    instructions: String,
+}
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // The warehouse, then every section after it is moves
+        let mut sections = parse::sections(input).into_iter();
+        let warehouse = sections.next().unwrap_or_default();
+        let grid = Grid::new(parse::char_grid(
+            warehouse,
+            |ch| "#.O@".contains(ch),
+            "#, ., O or @",
+        )?);
+        let mut instructions = String::new();
+        for line in sections.flatten() {
+            let moves = line.checked_chars(|ch| "<>^v".contains(ch), "a move (<, >, ^ or v)")?;
+            instructions.extend(moves);
+        }
+        if grid.find('@').is_none() {
+            return Err(ParseError::end_of_input(input, "the robot (@)").into());
+        }
+        Ok(Warehouse { grid, instructions })
+    }
+
+    // Simulate normal warehouse instructions
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut warehouse = input.grid.clone();
+        let mut robot_location = warehouse
+            .find('@')
+            .expect("Cannot find initial robot location");
+
+        for instruction in input.instructions.chars() {
+            try_move(&mut warehouse, &mut robot_location, instruction);
+        }
+
+        Ok(gps_sum(&warehouse).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut scaled_warehouse = scale_warehouse(&input.grid);
+        let mut scaled_robot_location = scaled_warehouse
+            .find('@')
+            .expect("Cannot find initial robot location");
+        for instruction in input.instructions.chars() {
+            try_scaled_move(
+                &mut scaled_warehouse,
+                &mut scaled_robot_location,
+                instruction,
+            );
+        }
+
+        Ok(gps_sum(&scaled_warehouse).into())
+    }
+}
+
+// The warehouse, a blank line, then the moves
+impl Lint for Day15 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let (sections, mut problems) = lint::sections(input);
+        let mut sections = sections.into_iter();
+        let warehouse = sections.next().unwrap_or_default();
+        problems.extend(lint::grid(
+            warehouse,
+            |ch| "#.O@".contains(ch),
+            "#, ., O or @",
+        ));
+        problems.extend(lint::markers(input, &[('@', "the robot (@)")]));
+        for line in sections.flatten() {
+            for (i, ch) in line.text.char_indices() {
+                if !"<>^v".contains(ch) {
+                    let token = &line.text[i..i + ch.len_utf8()];
+                    problems.push(line.error(token, "a move (<, >, ^ or v)"));
+                }
+            }
+        }
+        problems
+    }
+}
+
+impl Animate for Day15 {
+    // The scaled warehouse from part 2, before and after every instruction
+    fn frames(input: &Self::Input) -> Frames<'_> {
+        let mut scaled_warehouse = scale_warehouse(&input.grid);
+        let mut scaled_robot_location = scaled_warehouse
+            .find('@')
+            .expect("Cannot find initial robot location");
+        let start = Frame::new(scaled_warehouse.to_string(), "start");
+        let count = input.instructions.len();
+        let moves = input
+            .instructions
+            .chars()
+            .enumerate()
+            .map(move |(i, instruction)| {
+                try_scaled_move(
+                    &mut scaled_warehouse,
+                    &mut scaled_robot_location,
+                    instruction,
+                );
+                Frame::new(
+                    scaled_warehouse.to_string(),
+                    format!("move {}/{}: {}", i + 1, count, instruction),
+                )
+            });
+        Box::new(std::iter::once(start).chain(moves))
+    }
+}
+
+impl Inspect for Day15 {
+    type State = Shift;
+
+    fn state(input: &Self::Input) -> Shift {
+        Shift::new(input, false)
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "warehouse",
+                usage: "warehouse <1|2>",
+                help: "start over in the part 1 or the scaled part 2 warehouse",
+                run: |input, shift, args| {
+                    let part: u8 = repl::arg(args, 0, "1 or 2", None)?;
+                    if part != 1 && part != 2 {
+                        return Err(Error::msg(format!("Expected 1 or 2, got {}", part)));
+                    }
+                    *shift = Shift::new(input, part == 2);
+                    Ok(shift.caption(input))
+                },
+            },
+            Command {
+                name: "step",
+                usage: "step [N]",
+                help: "follow the next N moves, one by default",
+                run: |input, shift, args| {
+                    let count = repl::arg(args, 0, "a number of moves", Some(1))?;
+                    for instruction in input.instructions.chars().skip(shift.moves).take(count) {
+                        shift.step(instruction);
+                    }
+                    Ok(shift.caption(input))
+                },
+            },
+            Command {
+                name: "show",
+                usage: "show",
+                help: "draw the warehouse",
+                run: |input, shift, _| Ok(format!("{}{}", shift.grid, shift.caption(input))),
+            },
+            Command {
+                name: "cell",
+                usage: "cell ROW COL",
+                help: "what is on a tile",
+                run: |_, shift, args| {
+                    let point = GridPoint::new(
+                        repl::arg(args, 0, "a row", None)?,
+                        repl::arg(args, 1, "a column", None)?,
+                    );
+                    shift
+                        .grid
+                        .get(&point)
+                        .map(String::from)
+                        .ok_or_else(|| Error::msg("Outside the warehouse"))
+                },
+            },
+            Command {
+                name: "gps",
+                usage: "gps",
+                help: "the sum of the boxes' GPS coordinates so far",
+                run: |_, shift, _| Ok(gps_sum(&shift.grid).to_string()),
+            },
+        ]
+    }
+}
+
+// The robot part way through its moves, in either warehouse
+pub struct Shift {
+    scaled: bool,
+    grid: Grid,
+    robot: GridPoint,
+    moves: usize,
+}
+
+impl Shift {
+    fn new(input: &Warehouse, scaled: bool) -> Shift {
+        let grid = if scaled {
+            scale_warehouse(&input.grid)
+        } else {
+            input.grid.clone()
+        };
+        let robot = grid.find('@').expect("Cannot find initial robot location");
+        Shift {
+            scaled,
+            grid,
+            robot,
+            moves: 0,
+        }
+    }
+
+    fn step(&mut self, instruction: char) {
+        if self.scaled {
+            try_scaled_move(&mut self.grid, &mut self.robot, instruction);
+        } else {
+            try_move(&mut self.grid, &mut self.robot, instruction);
+        }
+        self.moves += 1;
+    }
+
+    fn caption(&self, input: &Warehouse) -> String {
+        let next = input.instructions.chars().nth(self.moves);
+        format!(
+            "move {}/{}, next {}",
+            self.moves,
+            input.instructions.len(),
+            next.map_or("none".to_string(), String::from)
+        )
+    }
+}
+
+fn try_move(warehouse: &mut Grid, robot_location: &mut GridPoint, instruction: char) {
+    match instruction {
+        '>' => try_instruction(warehouse, robot_location, Direction::Right),
+        '^' => try_instruction(warehouse, robot_location, Direction::Up),
+        '<' => try_instruction(warehouse, robot_location, Direction::Left),
+        'v' => try_instruction(warehouse, robot_location, Direction::Down),
+        _ => {}
+    }
+}
+
+fn try_scaled_move(warehouse: &mut Grid, robot_location: &mut GridPoint, instruction: char) {
+    match instruction {
+        // left and right are still the same
+        '>' => try_instruction(warehouse, robot_location, Direction::Right),
+        '<' => try_instruction(warehouse, robot_location, Direction::Left),
+        // note that only the up and down logic is changed by the boxes being 2-wide
+        '^' => try_scaled_instruction(warehouse, robot_location, Direction::Up),
+        'v' => try_scaled_instruction(warehouse, robot_location, Direction::Down),
+        _ => {}
+    }
+}
+
+// Every tile becomes two tiles wide, boxes become [ and ]
+fn scale_warehouse(warehouse: &Grid) -> Grid {
+    let mut scaled_grid: Vec<Vec<char>> = Vec::new();
+    for line in warehouse.rows() {
+        let mut row = Vec::new();
+        for ch in line {
+            match ch {
+                '#' => {
+                    row.push('#');
+                    row.push('#');
+                }
+                'O' => {
+                    row.push('[');
+                    row.push(']');
+                }
+                '@' => {
+                    row.push('@');
+                    row.push('.');
+                }
+                // parse only lets empty space through otherwise
+                _ => {
+                    row.push('.');
+                    row.push('.');
+                }
+            }
+        }
+        scaled_grid.push(row);
+    }
+    Grid::new(scaled_grid)
+}
+
+// Boxes are O in the part 1 warehouse and start with [ in the scaled one
+fn gps_sum(warehouse: &Grid) -> usize {
+    warehouse
+        .points_with(|&ch| ch == 'O' || ch == '[')
+        .map(|location| get_gps_coordinates(&location))
+        .sum()
+}
+
+fn get_gps_coordinates(location: &GridPoint) -> usize {
+    100 * location.index1 as usize + location.index2 as usize
+}
+
+fn try_instruction(warehouse: &mut Grid, current_location: &mut GridPoint, direction: Direction) {
+    let mut next_location = *current_location + direction.step();
+    let mut stack = vec!['.', '@'];
+    while let Some(ch) = warehouse.get(&next_location) {
+        if ch == '#' {
+            // Found a wall before an open space => cannot push
+            // => just exit without doing anything
+            return;
+        } else if ch == '.' {
+            // Found an open space before a wall
+            while let Some(pop_val) = stack.pop() {
+                warehouse.set(&next_location, pop_val);
+                next_location = next_location + direction.opposite().step();
+            }
+            // update robots location and exit early again
+            current_location.index1 += direction.step().index1;
+            current_location.index2 += direction.step().index2;
+            return;
+        } else {
+            // this means we found a box => add it to the list of what we are pushing
+            stack.push(ch);
+        }
+
+        next_location = next_location + direction.step();
+    }
+}
+
+fn try_scaled_instruction(
+    warehouse: &mut Grid,
+    current_location: &mut GridPoint,
+    direction: Direction,
+) {
+    let mut spaces_to_check = vec![*current_location + direction.step()];
+    let mut items_pushing = vec![('@', *current_location)];
+    while let Some(loc) = spaces_to_check.pop() {
+        // off the edge of the grid is as solid as a wall
+        let Some(ch) = warehouse.get(&loc) else {
+            return;
+        };
+        match ch {
+            '#' => {
+                // found a wall => cannot push => exit
+                return;
+            }
+            '[' => {
+                // this means the other half of the box is on the right
+                let next_location = loc + direction.step();
+
+                let this_side = ('[', loc);
+                if !items_pushing.contains(&this_side) {
+                    items_pushing.push(this_side);
+                    spaces_to_check.push(next_location);
+                }
+
+                let other_side = (']', loc + Direction::Right.step());
+                if !items_pushing.contains(&other_side) {
+                    items_pushing.push(other_side);
+                    spaces_to_check.push(next_location + Direction::Right.step());
+                }
+            }
+            ']' => {
+                // this means the other half of the box is on the left
+                let next_location = loc + direction.step();
+
+                let this_side = (']', loc);
+                if !items_pushing.contains(&this_side) {
+                    items_pushing.push(this_side);
+                    spaces_to_check.push(next_location);
+                }
+
+                let other_side = ('[', loc + Direction::Left.step());
+                if !items_pushing.contains(&other_side) {
+                    items_pushing.push(other_side);
+                    spaces_to_check.push(next_location + Direction::Left.step());
+                }
+            }
+            _ => {
+                // empty space, these boxes can move freely no other spaces to check from this loc
+                // (the robot never ends up in spaces_to_check, parse rejects anything else)
+            }
+        }
+    }
+    // if we made it out of the while loop that means we are able to push
+    // so we want to update the boxes locations from farthest to closest
+    // sort puts in order from top to bottom
+    //    => if going up that is what we want
+    //    => if going down we want the reverse
+    items_pushing.sort_unstable_by_key(|(_, p1)| p1.index1);
+    if direction == Direction::Down {
+        items_pushing.reverse();
+    }
+
+    for &(ch, loc) in &items_pushing {
+        // push the item
+        warehouse.set(&(loc + direction.step()), ch);
+        // it leaves a space where it was
+        warehouse.set(&loc, '.');
+    }
+    // update the robots location
+    current_location.index1 += direction.step().index1;
+    current_location.index2 += direction.step().index2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boxes_cannot_be_pushed_off_the_edge() {
+        let warehouse = Day15::parse(".O.\n.@.\n\n^\n").unwrap();
+        assert_eq!(Day15::part1(&warehouse).unwrap(), "1");
+        assert_eq!(Day15::part2(&warehouse).unwrap(), "2");
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day15::Day15;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day15>()?;
+
+    println!("{}", Day15::part1(&input)?);
+    println!("{}", Day15::part2(&input)?);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Direction, Grid, GridPoint};
+
+use crate::Day16;
+
+// size is the side of the square maze, rounded up to odd. The corridors are carved as a
+// spanning tree, so E can always be reached from S, then some walls are knocked through to
+// give more than one best path
+impl Generate for Day16 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(5) | 1;
+        let mut maze = Grid::filled((side, side), '#');
+        let start = GridPoint::new(side as i32 - 2, 1);
+        let end = GridPoint::new(1, side as i32 - 2);
+
+        // Cells sit on odd coordinates, with the walls between them on even ones
+        maze.set(&start, '.');
+        let mut stack = vec![start];
+        while let Some(&cell) = stack.last() {
+            let mut unvisited = Direction::ALL
+                .iter()
+                .map(|direction| (direction.step(), cell + direction.step() + direction.step()))
+                .filter(|(_, next)| {
+                    next.index1 > 0
+                        && next.index2 > 0
+                        && next.index1 < side as i32 - 1
+                        && next.index2 < side as i32 - 1
+                        && maze.get(next) == Some('#')
+                })
+                .collect::<Vec<(GridPoint, GridPoint)>>();
+            if unvisited.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut unvisited);
+            let (step, next) = unvisited[0];
+            maze.set(&(cell + step), '.');
+            maze.set(&next, '.');
+            stack.push(next);
+        }
+
+        for _ in 0..side * side / 20 {
+            let wall = GridPoint::new(
+                rng.range(1..=side as i64 - 2) as i32,
+                rng.range(1..=side as i64 - 2) as i32,
+            );
+            maze.set(&wall, '.');
+        }
+        maze.set(&start, 'S');
+        maze.set(&end, 'E');
+        Generated::new(maze.to_string())
+    }
+}
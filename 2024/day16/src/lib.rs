@@ -0,0 +1,228 @@
+mod generate;
+
+use anyhow::{Error, Result};
+use common::{
+    lint, parse,
+    search::{self, Search},
+    Animate, Answer, Frame, Frames, Lint, Params, ParseError, Solution,
+};
+use grid::{Direction, Grid, GridPoint};
+
+use std::collections::HashSet;
+
+pub struct Day16;
+
+// Moving forward costs a step, turning then moving costs a turn and a step
+const STEP_COST: usize = 1;
+const TURN_COST: usize = 1000;
+
+pub struct Maze {
+    grid: Grid,
+    costs: MoveCosts,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct MoveCosts {
+    step: usize,
+    turn: usize,
+}
+
+impl MoveCosts {
+    fn of_move(&self, turned: bool) -> usize {
+        if turned {
+            self.turn + self.step
+        } else {
+            self.step
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let input_grid = parse::char_grid(
+            parse::lines(input),
+            |ch| "#.SE".contains(ch),
+            "#, ., S or E",
+        )?;
+        let maze = Grid::new(input_grid);
+        for (tile, expected) in [('S', "the start tile (S)"), ('E', "the end tile (E)")] {
+            if maze.find(tile).is_none() {
+                return Err(ParseError::end_of_input(input, expected).into());
+            }
+        }
+        Ok(Maze {
+            grid: maze,
+            costs: MoveCosts {
+                step: params.get("step_cost", STEP_COST)?,
+                turn: params.get("turn_cost", TURN_COST)?,
+            },
+        })
+    }
+
+    fn part1(maze: &Self::Input) -> Result<Answer> {
+        let maze_search = search_maze(maze);
+        let (min_final_score, _) = best_finishes(&maze.grid, &maze_search)?;
+        Ok(min_final_score.into())
+    }
+
+    // Every tile on at least one of the best paths could be a seat
+    fn part2(maze: &Self::Input) -> Result<Answer> {
+        let maze_search = search_maze(maze);
+        let (_, finishes) = best_finishes(&maze.grid, &maze_search)?;
+        let seats = maze_search
+            .on_best_paths(finishes)
+            .into_iter()
+            .map(|reindeer| reindeer.location)
+            .collect::<HashSet<GridPoint>>();
+        Ok(seats.len().into())
+    }
+}
+
+impl Lint for Day16 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let mut problems = lint::grid(
+            parse::lines(input),
+            |ch| "#.SE".contains(ch),
+            "#, ., S or E",
+        );
+        problems.extend(lint::markers(
+            input,
+            &[('S', "the start tile (S)"), ('E', "the end tile (E)")],
+        ));
+        problems
+    }
+}
+
+impl Animate for Day16 {
+    // The search spreading through the maze, one frame per tile it reaches for the first time
+    fn frames(maze: &Self::Input) -> Frames<'_> {
+        let maze_search = search_maze(maze);
+        let best = best_finishes(&maze.grid, &maze_search).map(|(score, _)| score);
+        let mut seen = HashSet::new();
+        let reached = maze_search
+            .order()
+            .iter()
+            .map(|reindeer| reindeer.location)
+            .filter(|&location| seen.insert(location))
+            .collect::<Vec<GridPoint>>();
+        let count = reached.len();
+        let mut picture = maze.grid.clone();
+        Box::new(reached.into_iter().enumerate().map(move |(i, location)| {
+            if picture.get(&location) == Some('.') {
+                picture.set(&location, 'o');
+            }
+            let caption = match &best {
+                Ok(score) if i + 1 == count => {
+                    format!("{} tiles reached, best score {}", count, score)
+                }
+                Err(_) if i + 1 == count => format!("{} tiles reached, no way to E", count),
+                _ => format!("{} tiles reached", i + 1),
+            };
+            Frame::new(picture.to_string(), caption)
+        }))
+    }
+}
+
+// The lowest score each reindeer state can be reached with
+fn search_maze(maze: &Maze) -> Search<Reindeer, usize> {
+    let reindeer_start = Reindeer::new(
+        Direction::Right,
+        maze.grid.find('S').expect("Cannot find start location"),
+    );
+    search::dijkstra(reindeer_start, |&reindeer: &Reindeer| {
+        get_possible_step_directions(reindeer.facing_direction)
+            .into_iter()
+            .filter_map(move |direction| reindeer.take_step(direction, &maze.grid, maze.costs))
+    })
+}
+
+// The lowest score at the end tile, and the reindeer that finish with it
+fn best_finishes(
+    maze: &Grid,
+    maze_search: &Search<Reindeer, usize>,
+) -> Result<(usize, Vec<Reindeer>)> {
+    let end_location = maze.find('E').expect("Cannot find End location");
+    let finishes = maze_search
+        .distances()
+        .iter()
+        .filter(|(reindeer, _)| reindeer.location == end_location)
+        .map(|(&reindeer, &score)| (reindeer, score))
+        .collect::<Vec<(Reindeer, usize)>>();
+    let min_final_score = finishes
+        .iter()
+        .map(|&(_, score)| score)
+        .min()
+        .ok_or_else(|| Error::msg("The reindeer cannot reach the end tile"))?;
+    let best = finishes
+        .into_iter()
+        .filter(|&(_, score)| score == min_final_score)
+        .map(|(reindeer, _)| reindeer)
+        .collect();
+    Ok((min_final_score, best))
+}
+
+fn get_possible_step_directions(facing_direction: Direction) -> [Direction; 3] {
+    // straight ahead, or a turn to either side
+    [
+        facing_direction,
+        facing_direction.turn_back(),
+        facing_direction.turn(),
+    ]
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Reindeer {
+    facing_direction: Direction,
+    location: GridPoint,
+}
+
+impl Reindeer {
+    fn new(facing_direction: Direction, location: GridPoint) -> Reindeer {
+        Reindeer {
+            facing_direction,
+            location,
+        }
+    }
+
+    fn take_step(
+        &self,
+        next_dir: Direction,
+        maze: &Grid,
+        costs: MoveCosts,
+    ) -> Option<(Reindeer, usize)> {
+        let next_location = self.location + next_dir.step();
+        let score_increase = costs.of_move(next_dir != self.facing_direction);
+        // Off the edge of the grid is as blocked as a wall
+        match maze.get(&next_location) {
+            Some(tile) if tile != '#' => {
+                Some((Reindeer::new(next_dir, next_location), score_increase))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_grid_edge_blocks_like_a_wall() {
+        let maze = Day16::parse("S.E\n").unwrap();
+        assert_eq!(Day16::part1(&maze).unwrap(), "2");
+        assert_eq!(Day16::part2(&maze).unwrap(), "3");
+    }
+
+    #[test]
+    fn a_walled_off_end_is_an_error() {
+        let maze = Day16::parse("S#E\n").unwrap();
+        assert!(Day16::part1(&maze).is_err());
+        assert!(Day16::part2(&maze).is_err());
+    }
+}
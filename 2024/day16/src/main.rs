@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day16::Day16;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day16>()?;
+
+    println!("Min Score: {}", Day16::part1(&input)?);
+    println!("Number of Seat Locations: {}", Day16::part2(&input)?);
+
+    Ok(())
+}
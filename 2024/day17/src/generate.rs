@@ -0,0 +1,72 @@
+use common::{random::Rng, Generate, Generated, Solution};
+
+use crate::{Day17, ThreeBitComputer};
+
+// size is how many 3-bit digits Register A starts with, which is also how many values
+// part 1 prints. Programs have the same shape as the real ones: each pass outputs a value
+// worked out from the low bits of A, then shifts A down by three until it is zero. Only a
+// few constants make a program that can output itself, so candidates are tried until part 2
+// finds an answer
+impl Generate for Day17 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let digits = size.clamp(1, 21) as u32;
+        let low = 8_usize.pow(digits - 1);
+        let register_a = low + rng.below((low * 7) as u64) as usize;
+        loop {
+            let (x, y) = (rng.range(1..=7) as u8, rng.range(1..=7) as u8);
+            let program = if rng.one_in(2) {
+                vec![
+                    2,
+                    4,
+                    1,
+                    x,
+                    7,
+                    5,
+                    1,
+                    y,
+                    4,
+                    rng.range(0..=7) as u8,
+                    5,
+                    5,
+                    0,
+                    3,
+                    3,
+                    0,
+                ]
+            } else {
+                vec![
+                    2,
+                    4,
+                    1,
+                    x,
+                    7,
+                    5,
+                    4,
+                    rng.range(0..=7) as u8,
+                    1,
+                    y,
+                    5,
+                    5,
+                    0,
+                    3,
+                    3,
+                    0,
+                ]
+            };
+            let computer = ThreeBitComputer::new(register_a, 0, 0, program.clone());
+            if Day17::part2(&computer).is_err() {
+                continue;
+            }
+
+            let program = program
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>();
+            return Generated::new(format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                register_a,
+                program.join(",")
+            ));
+        }
+    }
+}
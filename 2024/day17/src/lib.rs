@@ -0,0 +1,408 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    parse::{self, Line},
+    repl,
+    repl::Command,
+    Answer, Inspect, Lint, Params, ParseError, Solution,
+};
+use std::collections::HashMap;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = ThreeBitComputer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut register_a = 0;
+        let mut register_b = 0;
+        let mut register_c = 0;
+        let mut program = Vec::new();
+
+        let mut found_program = false;
+
+        for line in parse::lines(input) {
+            match parse_entry(line)? {
+                Entry::Register('A', value) => register_a = value,
+                Entry::Register('B', value) => register_b = value,
+                Entry::Register(_, value) => register_c = value,
+                Entry::Program(values) => {
+                    program = values;
+                    found_program = true;
+                }
+                Entry::Blank => {}
+            }
+        }
+        if !found_program {
+            return Err(ParseError::end_of_input(input, "\"Program: \"").into());
+        }
+        Ok(ThreeBitComputer::new(
+            register_a, register_b, register_c, program,
+        ))
+    }
+
+    fn part1(computer: &Self::Input) -> Result<Answer> {
+        let mut computer = computer.clone();
+        computer.run();
+        Ok(Answer::Text(computer.get_output()))
+    }
+
+    // Find the lowest value of Register A that makes the program output itself
+    fn part2(computer: &Self::Input) -> Result<Answer> {
+        let mut computer = computer.clone();
+        let mut solution = None;
+        let mut potential_vals = vec![0];
+        'a: while let Some(val) = potential_vals.pop() {
+            for i in 0..8 {
+                let next_val = val + i;
+                computer.reset(next_val);
+                computer.run();
+                if computer.program == computer.output_stream {
+                    solution = Some(next_val);
+                    break 'a;
+                } else if computer.program.ends_with(&computer.output_stream)
+                    // A = 0 shifts back to 0, and a value that would lose bits off the top
+                    // could wrap around to one already tried
+                    && next_val != 0
+                    && next_val <= usize::MAX >> 3
+                {
+                    potential_vals.push(next_val << 3);
+                }
+            }
+        }
+        let solution = solution
+            .ok_or_else(|| anyhow::Error::msg("No value of Register A outputs the program"))?;
+        Ok(solution.into())
+    }
+}
+
+// Like parse, but each register and the program may only be given once
+impl Lint for Day17 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        let mut first_seen = HashMap::new();
+        for line in parse::lines(input) {
+            let name = match parse_entry(line) {
+                Ok(Entry::Register(register, _)) => format!("Register {}", register),
+                Ok(Entry::Program(_)) => "Program".to_string(),
+                Ok(Entry::Blank) => continue,
+                Err(problem) => {
+                    problems.push(problem);
+                    continue;
+                }
+            };
+            if let Some(first) = first_seen.insert(name.clone(), line.number) {
+                let expected = format!("{} only once, it is already on line {}", name, first);
+                problems.push(line.error(line.text, expected));
+            }
+        }
+        if !first_seen.contains_key("Program") {
+            problems.push(ParseError::end_of_input(input, "\"Program: \""));
+        }
+        problems
+    }
+}
+
+impl Inspect for Day17 {
+    type State = ThreeBitComputer;
+
+    fn state(computer: &Self::Input) -> ThreeBitComputer {
+        computer.clone()
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "registers",
+                usage: "registers",
+                help: "the registers, instruction pointer and next instruction",
+                run: |_, computer, _| Ok(computer.describe()),
+            },
+            Command {
+                name: "step",
+                usage: "step [N]",
+                help: "run N instructions, one by default",
+                run: |_, computer, args| {
+                    let count = repl::arg(args, 0, "a number of instructions", Some(1))?;
+                    for _ in 0..count {
+                        if computer.exit_code.is_some() {
+                            break;
+                        }
+                        computer.update();
+                    }
+                    Ok(computer.describe())
+                },
+            },
+            Command {
+                name: "run",
+                usage: "run",
+                help: "run until the program halts",
+                run: |_, computer, _| {
+                    computer.run();
+                    Ok(computer.describe())
+                },
+            },
+            Command {
+                name: "output",
+                usage: "output",
+                help: "everything output so far",
+                run: |_, computer, _| Ok(computer.get_output()),
+            },
+            Command {
+                name: "a",
+                usage: "a VALUE",
+                help: "start over with register A set to VALUE, as part 2 does",
+                run: |_, computer, args| {
+                    computer.reset(repl::arg(args, 0, "a register value", None)?);
+                    Ok(computer.describe())
+                },
+            },
+        ]
+    }
+}
+
+enum Entry {
+    Register(char, usize),
+    Program(Vec<u8>),
+    Blank,
+}
+
+fn parse_entry(line: Line) -> Result<Entry, ParseError> {
+    let input_string = line.text;
+    for register in ['A', 'B', 'C'] {
+        let prefix = format!("Register {}: ", register);
+        if input_string.starts_with(&prefix) {
+            let value = line.number(line.strip_prefix(input_string, &prefix)?)?;
+            return Ok(Entry::Register(register, value));
+        }
+    }
+    if input_string.starts_with("Program: ") {
+        let program = line
+            .strip_prefix(input_string, "Program: ")?
+            .split(',')
+            .map(|v| match line.number::<u8>(v) {
+                Ok(val) if val < 8 => Ok(val),
+                _ => Err(line.error(v.trim(), "a 3-bit number")),
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        Ok(Entry::Program(program))
+    } else if input_string.trim().is_empty() {
+        Ok(Entry::Blank)
+    } else {
+        Err(line.error(input_string, "a register or the program"))
+    }
+}
+
+#[derive(Clone)]
+pub struct ThreeBitComputer {
+    register_a: usize,
+    register_b: usize,
+    register_c: usize,
+    instruction_pointer: usize,
+    program: Vec<u8>,
+    output_stream: Vec<u8>,
+    exit_code: Option<u8>,
+}
+
+impl ThreeBitComputer {
+    // Back to the start of the program with register A set to val and nothing output
+    pub fn reset(&mut self, val: usize) {
+        self.register_a = val;
+        self.register_b = 0;
+        self.register_c = 0;
+        self.instruction_pointer = 0;
+        self.output_stream.clear();
+        self.exit_code = None;
+    }
+
+    pub fn new(
+        register_a: usize,
+        register_b: usize,
+        register_c: usize,
+        program: Vec<u8>,
+    ) -> ThreeBitComputer {
+        ThreeBitComputer {
+            register_a,
+            register_b,
+            register_c,
+            instruction_pointer: 0,
+            program,
+            output_stream: Vec::new(),
+            exit_code: None,
+        }
+    }
+
+    // Runs until the program halts
+    pub fn run(&mut self) {
+        while self.exit_code.is_none() {
+            self.update();
+        }
+    }
+
+    pub fn get_output(&self) -> String {
+        // comma separated output
+        let mut output = String::new();
+        for val in &self.output_stream {
+            if !output.is_empty() {
+                output += ",";
+            }
+            output += val.to_string().as_str();
+        }
+        output
+    }
+
+    // Registers A, B and C
+    pub fn registers(&self) -> [usize; 3] {
+        [self.register_a, self.register_b, self.register_c]
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output_stream
+    }
+
+    // 0 when the program ran off its end, 1 for an unknown opcode and 2 for an invalid
+    // combo operand
+    pub fn exit_code(&self) -> Option<u8> {
+        self.exit_code
+    }
+
+    fn describe(&self) -> String {
+        let rest = &self.program[self.instruction_pointer.min(self.program.len())..];
+        let next = match (self.exit_code, rest) {
+            (Some(code), _) => format!("halted with {}", code),
+            (None, [opcode, operand, ..]) => format!("next {} {}", opcode, operand),
+            (None, _) => "next halts".to_string(),
+        };
+        format!(
+            "A {}, B {}, C {}, pointer {}, {}",
+            self.register_a, self.register_b, self.register_c, self.instruction_pointer, next
+        )
+    }
+
+    fn halt(&mut self, code: u8) {
+        self.exit_code = Some(code)
+    }
+
+    // Runs the instruction at the instruction pointer
+    pub fn update(&mut self) {
+        if let Some(opcode) = self.program.get(self.instruction_pointer) {
+            if let Some(operand) = self.program.get(self.instruction_pointer + 1) {
+                match opcode {
+                    0 => self.adv(*operand),
+                    1 => self.bxl(*operand),
+                    2 => self.bst(*operand),
+                    3 => self.jnz(*operand),
+                    4 => self.bxc(*operand),
+                    5 => self.out(*operand),
+                    6 => self.bdv(*operand),
+                    7 => self.cdv(*operand),
+                    _ => self.halt(1),
+                }
+            } else {
+                // an opcode without its operand halts too
+                self.halt(0);
+            }
+        } else {
+            // cannot read opcode -> program halts
+            self.halt(0);
+        }
+    }
+
+    fn combo_operand(&mut self, operand: u8) -> usize {
+        match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.register_a,
+            5 => self.register_b,
+            6 => self.register_c,
+            7..=u8::MAX => {
+                self.halt(2);
+                2
+            }
+        }
+    }
+
+    fn adv(&mut self, operand: u8) {
+        self.register_a >>= self.combo_operand(operand);
+        self.instruction_pointer += 2;
+    }
+
+    fn bxl(&mut self, operand: u8) {
+        self.register_b ^= operand as usize;
+        self.instruction_pointer += 2;
+    }
+
+    fn bst(&mut self, operand: u8) {
+        self.register_b = self.combo_operand(operand) % 8;
+        self.instruction_pointer += 2;
+    }
+
+    fn jnz(&mut self, operand: u8) {
+        if self.register_a != 0 {
+            self.instruction_pointer = operand as usize;
+        } else {
+            self.instruction_pointer += 2;
+        }
+    }
+
+    fn bxc(&mut self, _: u8) {
+        self.register_b ^= self.register_c;
+        self.instruction_pointer += 2;
+    }
+
+    fn out(&mut self, operand: u8) {
+        let val = (self.combo_operand(operand) % 8) as u8;
+        self.output_stream.push(val);
+        self.instruction_pointer += 2;
+    }
+
+    fn bdv(&mut self, operand: u8) {
+        self.register_b = self.register_a >> self.combo_operand(operand);
+        self.instruction_pointer += 2;
+    }
+
+    fn cdv(&mut self, operand: u8) {
+        self.register_c = self.register_a >> self.combo_operand(operand);
+        self.instruction_pointer += 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The small programs worked through in the puzzle text
+    #[test]
+    fn runs_the_puzzle_examples() {
+        let mut computer = ThreeBitComputer::new(0, 0, 9, vec![2, 6]);
+        computer.run();
+        assert_eq!(computer.registers()[1], 1);
+        assert_eq!(computer.exit_code(), Some(0));
+
+        let mut computer = ThreeBitComputer::new(10, 0, 0, vec![5, 0, 5, 1, 5, 4]);
+        computer.run();
+        assert_eq!(computer.get_output(), "0,1,2");
+
+        let mut computer = ThreeBitComputer::new(2024, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        computer.update();
+        assert_eq!(computer.registers()[0], 1012);
+        assert_eq!(computer.instruction_pointer(), 2);
+        computer.run();
+        assert_eq!(computer.output(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.registers()[0], 0);
+
+        computer.reset(2024);
+        assert!(computer.output().is_empty() && computer.exit_code().is_none());
+    }
+}
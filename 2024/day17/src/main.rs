@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day17::Day17;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let computer = input::parse_from_args::<Day17>()?;
+
+    println!("Program Output: {}", Day17::part1(&computer)?);
+    println!("Solution Found: {}", Day17::part2(&computer)?);
+
+    Ok(())
+}
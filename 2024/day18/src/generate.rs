@@ -0,0 +1,65 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Direction, Grid, GridPoint};
+
+use std::collections::VecDeque;
+
+use crate::Day18;
+
+// size is the side of the memory space. Bytes fall on every tile but the corners, in random
+// order, until a few after the exit gets cut off. byte_count is somewhere before that point,
+// so part 1 always has a path
+impl Generate for Day18 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(3);
+        let exit = GridPoint::new(side as i32 - 1, side as i32 - 1);
+        let mut bytes = Grid::filled((side, side), '.')
+            .points()
+            .filter(|point| *point != GridPoint::new(0, 0) && *point != exit)
+            .collect::<Vec<GridPoint>>();
+        rng.shuffle(&mut bytes);
+
+        // The most bytes that can fall with the exit still reachable
+        let (mut open, mut blocked) = (0, bytes.len());
+        while open + 1 < blocked {
+            let middle = (open + blocked) / 2;
+            if exit_reachable(side, &bytes[..middle]) {
+                open = middle;
+            } else {
+                blocked = middle;
+            }
+        }
+        let byte_count = rng.range((open as i64 / 2).max(1)..=open as i64);
+        bytes.truncate((blocked + 5).min(bytes.len()));
+
+        let mut text = String::new();
+        for byte in bytes {
+            text += &format!("{},{}\n", byte.index1, byte.index2);
+        }
+        Generated::new(text)
+            .with_param("byte_count", byte_count)
+            .with_param("grid_size", side)
+    }
+}
+
+fn exit_reachable(side: usize, bytes: &[GridPoint]) -> bool {
+    let mut memory = Grid::filled((side, side), '.');
+    for byte in bytes {
+        memory.set(byte, '#');
+    }
+    let exit = GridPoint::new(side as i32 - 1, side as i32 - 1);
+    let mut queue = VecDeque::from([GridPoint::new(0, 0)]);
+    memory.set(&GridPoint::new(0, 0), 'O');
+    while let Some(point) = queue.pop_front() {
+        if point == exit {
+            return true;
+        }
+        for direction in Direction::ALL {
+            let next = point + direction.step();
+            if memory.get(&next) == Some('.') {
+                memory.set(&next, 'O');
+                queue.push_back(next);
+            }
+        }
+    }
+    false
+}
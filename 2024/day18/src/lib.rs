@@ -0,0 +1,172 @@
+mod generate;
+
+use anyhow::{Error, Result};
+use common::{
+    lint::Positions,
+    parse::{self, Line},
+    search, Answer, Lint, Params, ParseError, Picture, Solution,
+};
+use grid::{Direction, Grid, GridPoint};
+
+// problem values, the example uses byte_count=12 and grid_size=7
+const BYTE_COUNT: usize = 1024;
+const GRID_SIZE: usize = 71;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = MemorySpace;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let corrupted_coords = parse::lines(input)
+            .map(parse_coordinate)
+            .collect::<Result<Vec<GridPoint>, _>>()?;
+        let byte_count = params.get("byte_count", BYTE_COUNT)?;
+        if byte_count > corrupted_coords.len() {
+            return Err(Error::msg(format!(
+                "byte_count is {} but only {} bytes fall",
+                byte_count,
+                corrupted_coords.len()
+            )));
+        }
+        Ok(MemorySpace {
+            corrupted_coords,
+            byte_count,
+            grid_size: params.get("grid_size", GRID_SIZE)?,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        let memory_grid = memory.corrupted_grid();
+        let minimum_steps = find_path(&memory_grid, GridPoint::new(0, 0), memory.exit())
+            .ok_or_else(|| Error::msg("No path to the exit once byte_count bytes have fallen"))?;
+        Ok(minimum_steps.into())
+    }
+
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        // The first byte_count bytes are known to leave a path open, unless they don't, in
+        // which case the blocking byte is among them
+        let mut memory_grid = memory.corrupted_grid();
+        let start = match find_path(&memory_grid, GridPoint::new(0, 0), memory.exit()) {
+            Some(_) => memory.byte_count,
+            None => {
+                memory_grid = Grid::new(vec![vec!['.'; memory.grid_size]; memory.grid_size]);
+                0
+            }
+        };
+        // This is slow, (I should be checking if the byte drops in the current path
+        // And reusing work from the previous step) but it is fast enough
+        for byte in &memory.corrupted_coords[start..] {
+            memory_grid.set(byte, '#');
+            if find_path(&memory_grid, GridPoint::new(0, 0), memory.exit()).is_none() {
+                return Ok(Answer::Coordinate(byte.index1.into(), byte.index2.into()));
+            }
+        }
+        Err(Error::msg(
+            "The exit can still be reached after every byte falls",
+        ))
+    }
+}
+
+// Every byte falls inside the memory space, and no two fall in the same place
+impl Lint for Day18 {
+    fn lint(input: &str, params: &Params) -> Vec<ParseError> {
+        let grid_size = params.get("grid_size", GRID_SIZE).unwrap_or(GRID_SIZE) as i64;
+        let mut positions = Positions::new(grid_size, grid_size, true);
+        let mut problems = Vec::new();
+        for line in parse::lines(input) {
+            match parse_coordinate(line) {
+                Ok(coordinate) => problems.extend(positions.check(
+                    &line,
+                    line.text.trim(),
+                    coordinate.index1.into(),
+                    coordinate.index2.into(),
+                )),
+                Err(problem) => problems.push(problem),
+            }
+        }
+        problems
+    }
+}
+
+fn parse_coordinate(line: Line) -> Result<GridPoint, ParseError> {
+    let (index1, index2) = line.split_once(line.text, ",")?;
+    Ok(GridPoint::new(line.number(index1)?, line.number(index2)?))
+}
+
+// The memory space as part 1 sees it
+impl Picture for Day18 {
+    fn picture(memory: &Self::Input) -> Result<String> {
+        Ok(memory.corrupted_grid().to_string())
+    }
+}
+
+pub struct MemorySpace {
+    corrupted_coords: Vec<GridPoint>,
+    byte_count: usize,
+    grid_size: usize,
+}
+
+impl MemorySpace {
+    // The memory space once the first byte_count bytes have fallen
+    fn corrupted_grid(&self) -> Grid {
+        let mut memory_grid = Grid::new(vec![vec!['.'; self.grid_size]; self.grid_size]);
+        for corrupted in self.corrupted_coords.iter().take(self.byte_count) {
+            memory_grid.set(corrupted, '#');
+        }
+        memory_grid
+    }
+
+    fn exit(&self) -> GridPoint {
+        GridPoint::new(self.grid_size as i32 - 1, self.grid_size as i32 - 1)
+    }
+}
+
+fn find_path(memory_grid: &Grid, start: GridPoint, stop: GridPoint) -> Option<usize> {
+    let steps = search::bfs(start, |&loc: &GridPoint| {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| loc + direction.step())
+            .filter(|next_location| memory_grid.get(next_location) == Some('.'))
+    });
+    steps.distance(&stop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(byte_count: &str) -> Result<MemorySpace> {
+        let mut params = Params::new();
+        params.set("byte_count", byte_count);
+        params.set("grid_size", "7");
+        Day18::parse_with(include_str!("../puzzle_input_example.txt"), &params)
+    }
+
+    #[test]
+    fn byte_count_must_fit_the_input() {
+        assert!(example("26").is_err());
+        assert!(Day18::parse(include_str!("../puzzle_input_example.txt")).is_err());
+    }
+
+    #[test]
+    fn part2_looks_among_the_first_bytes_when_they_already_block() {
+        let memory = example("25").unwrap();
+        assert!(Day18::part1(&memory).is_err());
+        assert_eq!(Day18::part2(&memory).unwrap(), "6,1");
+        assert_eq!(Day18::part2(&example("0").unwrap()).unwrap(), "6,1");
+    }
+
+    #[test]
+    fn part2_fails_when_the_exit_stays_open() {
+        let mut params = Params::new();
+        params.set("byte_count", "1");
+        params.set("grid_size", "3");
+        let memory = Day18::parse_with("0,1\n", &params).unwrap();
+        assert!(Day18::part2(&memory).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day18::Day18;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day18>()?;
+
+    println!("Minimum steps: {}", Day18::part1(&input)?);
+    println!("Blocking byte location: {}", Day18::part2(&input)?);
+
+    Ok(())
+}
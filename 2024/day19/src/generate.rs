@@ -0,0 +1,41 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day19;
+
+const STRIPES: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+// size is the number of designs. One stripe color never gets a towel of its own, like in the
+// real input, so not every design can be made. Most designs are built out of towels and the
+// rest are random stripes
+impl Generate for Day19 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let missing = *rng.pick(&STRIPES);
+        let towel_count = rng.range(8..=40) as usize;
+        let mut towels: Vec<String> = Vec::new();
+        while towels.len() < towel_count {
+            let towel = (0..rng.range(1..=8))
+                .map(|_| *rng.pick(&STRIPES))
+                .collect::<String>();
+            if towel != missing.to_string() && !towels.contains(&towel) {
+                towels.push(towel);
+            }
+        }
+
+        let mut designs = Vec::new();
+        for _ in 0..size.max(1) {
+            let length = rng.range(20..=50) as usize;
+            let from_towels = !rng.one_in(4);
+            let mut design = String::new();
+            while design.len() < length {
+                if from_towels {
+                    design += rng.pick(&towels).as_str();
+                } else {
+                    design.push(*rng.pick(&STRIPES));
+                }
+            }
+            designs.push(design);
+        }
+
+        Generated::new(format!("{}\n\n{}\n", towels.join(", "), designs.join("\n")))
+    }
+}
@@ -0,0 +1,168 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint,
+    memo::{self, Stats},
+    parse::{self, Line},
+    Answer, Lint, Memo, Params, ParseError, Solution,
+};
+
+pub struct Day19;
+
+pub struct Onsen {
+    towel_patterns: Vec<String>,
+    display_patterns: Vec<String>,
+    memo_limit: Option<usize>,
+}
+
+impl Onsen {
+    pub fn towels(&self) -> &[String] {
+        &self.towel_patterns
+    }
+
+    pub fn designs(&self) -> &[String] {
+        &self.display_patterns
+    }
+}
+
+impl Solution for Day19 {
+    type Input = Onsen;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let (towels, designs) = parse::two_sections(input, "a blank line, then the designs")?;
+        let towel_patterns = parse_towels(towels[0])?;
+        if let Some(line) = towels.get(1) {
+            return Err(line
+                .error(line.text, "a blank line after the towels")
+                .into());
+        }
+        let display_patterns = designs
+            .into_iter()
+            .map(parse_design)
+            .collect::<Result<_, _>>()?;
+        Ok(Onsen {
+            towel_patterns,
+            display_patterns,
+            memo_limit: memo::limit(params)?,
+        })
+    }
+
+    fn part1(onsen: &Self::Input) -> Result<Answer> {
+        let mut possible_displays = 0;
+        for display_pattern in &onsen.display_patterns {
+            let mut full_check_list = Vec::new();
+            let mut found_soluton = false;
+            let mut queue = vec![display_pattern.clone()];
+            while let Some(display) = queue.pop() {
+                for towel in &onsen.towel_patterns {
+                    if display.starts_with(towel) {
+                        let x = display.chars().skip(towel.len()).collect::<String>();
+                        if x.is_empty() {
+                            possible_displays += 1;
+                            queue.clear();
+                            found_soluton = true;
+                        } else if !full_check_list.contains(&x)
+                            && !queue.contains(&x)
+                            && !found_soluton
+                        {
+                            queue.push(x.clone());
+                            full_check_list.push(x);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(possible_displays.into())
+    }
+
+    fn part2(onsen: &Self::Input) -> Result<Answer> {
+        Ok(count_all_ways(onsen).0.into())
+    }
+}
+
+// The towels on one line, a blank line, then the designs
+impl Lint for Day19 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let (sections, mut problems) = lint::sections(input);
+        let mut sections = sections.into_iter();
+        let towels = sections.next().unwrap_or_default();
+        problems.extend(towels.first().and_then(|&line| parse_towels(line).err()));
+        for line in towels.iter().skip(1) {
+            problems.push(line.error(line.text, "a blank line after the towels"));
+        }
+        match sections.next() {
+            Some(designs) => problems.extend(
+                designs
+                    .into_iter()
+                    .filter_map(|line| parse_design(line).err()),
+            ),
+            None => problems.push(ParseError::end_of_input(
+                input,
+                "a blank line, then the designs",
+            )),
+        }
+        problems.extend(
+            sections
+                .flatten()
+                .map(|line| line.error(line.text, "end of input")),
+        );
+        problems
+    }
+}
+
+fn is_stripe(ch: char) -> bool {
+    "wubrg".contains(ch)
+}
+
+fn parse_towels(line: Line) -> Result<Vec<String>, ParseError> {
+    let mut towel_patterns = Vec::new();
+    for towel in line.text.split(',') {
+        let towel = towel.trim();
+        if towel.is_empty() || !towel.chars().all(is_stripe) {
+            return Err(line.error(towel, "a towel pattern of w, u, b, r and g"));
+        }
+        towel_patterns.push(towel.to_string());
+    }
+    Ok(towel_patterns)
+}
+
+fn parse_design(line: Line) -> Result<String, ParseError> {
+    line.checked_chars(is_stripe, "a stripe color (w, u, b, r or g)")?;
+    Ok(line.text.to_string())
+}
+
+// The number of ways to make every design, and how well the memo did.
+// Designs share their endings, so one memo is kept across all of them
+pub fn count_all_ways(onsen: &Onsen) -> (usize, Stats) {
+    let mut memo = Memo::with_limit(onsen.memo_limit);
+    let unique_ways = onsen
+        .display_patterns
+        .iter()
+        .map(|display_pattern| count_ways(display_pattern, &onsen.towel_patterns, &mut memo))
+        .sum();
+    (unique_ways, memo.stats())
+}
+
+// The number of ways to make a design out of the towels. Every suffix is a slice of the
+// design, so the memo never needs its own copy
+pub fn count_ways<'a>(
+    display_pattern: &'a str,
+    towel_patterns: &[String],
+    memo: &mut Memo<&'a str, usize>,
+) -> usize {
+    if display_pattern.is_empty() {
+        return 1;
+    }
+    memo.get_or_insert_with(display_pattern, |memo| {
+        towel_patterns
+            .iter()
+            .filter_map(|towel| display_pattern.strip_prefix(towel.as_str()))
+            .map(|rest| count_ways(rest, towel_patterns, memo))
+            .sum()
+    })
+}
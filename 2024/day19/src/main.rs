@@ -0,0 +1,15 @@
+use anyhow::Result;
+use aoc2024_day19::Day19;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day19>()?;
+
+    println!("Possible arrangements: {}", Day19::part1(&input)?);
+    // Part 2 by hand, so the memo stats come from the same count as the answer
+    let (ways, stats) = aoc2024_day19::count_all_ways(&input);
+    println!("Unique arrangements: {}", ways);
+    println!("Memo: {}", stats);
+
+    Ok(())
+}
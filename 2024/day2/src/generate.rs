@@ -0,0 +1,27 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day2;
+
+// size is the number of reports. Most of them change steadily, with the odd level that
+// breaks the rules
+impl Generate for Day2 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            let direction = if rng.one_in(2) { 1 } else { -1 };
+            let mut level = rng.range(30..=70);
+            let mut levels = vec![level.to_string()];
+            for _ in 1..rng.range(5..=8) {
+                level += if rng.one_in(6) {
+                    rng.range(-4..=4)
+                } else {
+                    direction * rng.range(1..=3)
+                };
+                levels.push(level.to_string());
+            }
+            text += &levels.join(" ");
+            text.push('\n');
+        }
+        Generated::new(text)
+    }
+}
@@ -0,0 +1,92 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint,
+    parse::{self, Line},
+    Answer, Lint, Params, ParseError, Solution,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)
+            .map(parse_line)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Answer> {
+        let safe_count = reports.iter().filter(|levels| is_safe(levels)).count();
+        Ok(safe_count.into())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Answer> {
+        let dampener_safe_count = reports
+            .iter()
+            .filter(|levels| is_safe(levels) || is_safe_with_dampener(levels))
+            .count();
+        Ok(dampener_safe_count.into())
+    }
+}
+
+impl Lint for Day2 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::each_line(input, parse_line)
+    }
+}
+
+fn parse_line(line: Line) -> Result<Vec<i64>, ParseError> {
+    let mut levels = Vec::new();
+    for str_val in line.text.split_whitespace() {
+        levels.push(line.number(str_val)?);
+    }
+    if levels.is_empty() {
+        return Err(line.error(line.end(), "a report of levels"));
+    }
+    Ok(levels)
+}
+
+// All increasing or all decreasing, by 1 to 3 each time
+pub fn is_safe(levels: &[i64]) -> bool {
+    let mut diffs = Vec::new();
+    for pair in levels.windows(2) {
+        diffs.push(pair[1] - pair[0]);
+    }
+    // Now that I have the differences between levels, I can just check for the fail cases and return early
+    // if none of the fail cases are satisfied it is safe
+    if diffs.contains(&0) {
+        // No change
+        return false;
+    }
+    if diffs.iter().min().is_some_and(|x| x.is_negative())
+        && diffs.iter().max().is_some_and(|x| x.is_positive())
+    {
+        // Increase and decrease
+        return false;
+    }
+    if !diffs
+        .into_iter()
+        .filter(|x| x.abs() > 3)
+        .collect::<Vec<i64>>()
+        .is_empty()
+    {
+        // There was at least 1 change that was larger than 3
+        return false;
+    }
+    true
+}
+
+// Safe once any single level is removed
+pub fn is_safe_with_dampener(levels: &[i64]) -> bool {
+    let mut dampener_safe = Vec::new();
+    for i in 0..levels.len() {
+        let mut dampened_levels = levels.to_vec();
+        dampened_levels.remove(i);
+        dampener_safe.push(is_safe(&dampened_levels));
+    }
+
+    dampener_safe.contains(&true)
+}
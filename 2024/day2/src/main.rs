@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day2::Day2;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day2>()?;
+
+    println!("Number of Safe Reports: {}", Day2::part1(&input)?);
+    println!("Number of Dampener Safe Reports: {}", Day2::part2(&input)?);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day3;
+
+const JUNK: [&str; 10] = [
+    "mul(4*",
+    "mul[3,7]",
+    "%&",
+    "select()",
+    "+mul(32,64]",
+    "from()",
+    "]then(",
+    "mul ( 2 , 4 )",
+    "?why()",
+    "do_not_mul(5,5)",
+];
+
+// size is the number of real mul instructions, mixed in with junk, do() and don't()
+impl Generate for Day3 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut text = String::new();
+        for i in 0..size.max(1) {
+            for _ in 0..rng.range(0..=3) {
+                text += *rng.pick(&JUNK);
+            }
+            if rng.one_in(8) {
+                text += if rng.one_in(2) { "do()" } else { "don't()" };
+            }
+            text += &format!("mul({},{})", rng.range(1..=999), rng.range(1..=999));
+            if i % 40 == 39 {
+                text.push('\n');
+            }
+        }
+        text.push('\n');
+        Generated::new(text)
+    }
+}
@@ -0,0 +1,63 @@
+mod generate;
+
+use anyhow::Result;
+use common::{Answer, Lint, Params, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(full_instructions: &Self::Input) -> Result<Answer> {
+        let mut total = 0;
+        let mul_regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+
+        let regex_match = mul_regex.find_iter(full_instructions);
+        for mul_match in regex_match {
+            if let Some((a, b)) = mul_match.as_str().split_once(',') {
+                let a_clean = a.trim_start_matches("mul(").parse::<u64>()?;
+                let b_clean = b.trim_end_matches(")").parse::<u64>()?;
+                total += a_clean * b_clean;
+            }
+        }
+        Ok(total.into())
+    }
+
+    // Only run mul()s between a do() and a don't()
+    fn part2(full_instructions: &Self::Input) -> Result<Answer> {
+        let mut conditional_total = 0;
+        let do_regex = Regex::new(r"do\(\)").unwrap();
+        let dont_regex = Regex::new(r"don\'t\(\)").unwrap();
+        let mul_regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+
+        let do_match_split = do_regex.split(full_instructions);
+        // each section starts right after a do()
+        for section in do_match_split {
+            // grab everything until we find a don't()
+            let instructions_to_run = dont_regex.split(section).take(1).collect::<String>();
+
+            // now do the mul()s only on the parts that come after a do() but before a don't()
+            let regex_match = mul_regex.find_iter(instructions_to_run.as_str());
+            for mul_match in regex_match {
+                if let Some((a, b)) = mul_match.as_str().split_once(',') {
+                    let a_clean = a.trim_start_matches("mul(").parse::<u64>()?;
+                    let b_clean = b.trim_end_matches(")").parse::<u64>()?;
+                    conditional_total += a_clean * b_clean;
+                }
+            }
+        }
+        Ok(conditional_total.into())
+    }
+}
+
+// Corrupted memory, so any text at all is a valid input
+impl Lint for Day3 {
+    fn lint(_input: &str, _params: &Params) -> Vec<ParseError> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day3::Day3;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day3>()?;
+
+    println!("Total: {}", Day3::part1(&input)?);
+    println!("Conditional Total: {}", Day3::part2(&input)?);
+
+    Ok(())
+}
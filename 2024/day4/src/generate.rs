@@ -0,0 +1,17 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day4;
+
+// size is the side of the square word search
+impl Generate for Day4 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..size.max(1) {
+                text.push(*rng.pick(&['X', 'M', 'A', 'S']));
+            }
+            text.push('\n');
+        }
+        Generated::new(text)
+    }
+}
@@ -0,0 +1,102 @@
+mod generate;
+
+use anyhow::Result;
+use common::{lint, parse, Answer, Lint, Params, ParseError, Solution};
+use grid::{Grid, GridPoint};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_grid = parse::char_grid(
+            parse::lines(input),
+            |ch| "XMAS".contains(ch),
+            "one of X, M, A or S",
+        )?;
+        Ok(Grid::new(input_grid))
+    }
+
+    fn part1(char_grid: &Self::Input) -> Result<Answer> {
+        Ok(count_xmases(char_grid).into())
+    }
+
+    fn part2(char_grid: &Self::Input) -> Result<Answer> {
+        Ok(count_x_mases(char_grid).into())
+    }
+}
+
+impl Lint for Day4 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::grid(
+            parse::lines(input),
+            |ch| "XMAS".contains(ch),
+            "one of X, M, A or S",
+        )
+    }
+}
+
+fn count_xmases(char_grid: &Grid) -> usize {
+    let target = ['X', 'M', 'A', 'S'];
+    let offsets = [
+        GridPoint::new(1, 0),
+        GridPoint::new(1, 1),
+        GridPoint::new(0, 1),
+        GridPoint::new(-1, 1),
+        GridPoint::new(-1, 0),
+        GridPoint::new(-1, -1),
+        GridPoint::new(0, -1),
+        GridPoint::new(1, -1),
+    ];
+    let mut found_count = 0;
+    let x_locations = char_grid.find_all(target[0]);
+    for x_location in x_locations {
+        for offset in &offsets {
+            let mut target_index = 1;
+            let mut next_location = x_location + *offset;
+            while target_index < target.len() {
+                if char_grid.get(&next_location) == Some(target[target_index]) {
+                    next_location = next_location + *offset;
+                    target_index += 1;
+                    if target_index == target.len() {
+                        found_count += 1;
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+    found_count
+}
+
+fn count_x_mases(char_grid: &Grid) -> usize {
+    let x_pattern_offsets = [
+        GridPoint::new(1, 1),
+        GridPoint::new(-1, -1),
+        GridPoint::new(1, -1),
+        GridPoint::new(-1, 1),
+    ];
+
+    let x_mas_solutions = [
+        ['M', 'S', 'M', 'S'],
+        ['M', 'S', 'S', 'M'],
+        ['S', 'M', 'M', 'S'],
+        ['S', 'M', 'S', 'M'],
+    ];
+
+    let mut found_count2 = 0;
+    let a_locations = char_grid.find_all('A');
+    for a_location in a_locations {
+        let mut x_pattern = [' '; 4];
+        for (i, offset) in x_pattern_offsets.iter().enumerate() {
+            x_pattern[i] = char_grid.get(&(a_location + *offset)).unwrap_or('.')
+        }
+        if x_mas_solutions.contains(&x_pattern) {
+            found_count2 += 1;
+        }
+    }
+
+    found_count2
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day4::Day4;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day4>()?;
+
+    println!("XMASes Found: {}", Day4::part1(&input)?);
+    println!("X-MASes Found: {}", Day4::part2(&input)?);
+
+    Ok(())
+}
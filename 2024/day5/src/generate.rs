@@ -0,0 +1,39 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day5;
+
+// size is the number of updates. The pages have a hidden order and every pair of them gets
+// a rule, like the real input, so every update has exactly one correct order
+impl Generate for Day5 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut pages = (10..=99).collect::<Vec<u32>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(rng.range(10..=49) as usize);
+
+        let mut rules = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in &pages[i + 1..] {
+                rules.push(format!("{}|{}", before, after));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let mut updates = Vec::new();
+        for _ in 0..size.max(1) {
+            let length = rng.range(2..=(pages.len() as i64 / 2).min(11)) * 2 - 1;
+            let mut indices = (0..pages.len()).collect::<Vec<usize>>();
+            rng.shuffle(&mut indices);
+            indices.truncate(length as usize);
+            if rng.one_in(2) {
+                indices.sort_unstable();
+            }
+            let update = indices
+                .iter()
+                .map(|&i| pages[i].to_string())
+                .collect::<Vec<String>>();
+            updates.push(update.join(","));
+        }
+
+        Generated::new(format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n")))
+    }
+}
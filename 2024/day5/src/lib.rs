@@ -0,0 +1,152 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint,
+    parse::{self, Line},
+    Answer, Lint, Params, ParseError, Solution,
+};
+
+pub struct Day5;
+
+pub struct PrintQueue {
+    order_rules: Vec<[u32; 2]>,
+    update_orders: Vec<Vec<u32>>,
+}
+
+impl PrintQueue {
+    // Each rule is [before, after]
+    pub fn rules(&self) -> &[[u32; 2]] {
+        &self.order_rules
+    }
+
+    pub fn updates(&self) -> &[Vec<u32>] {
+        &self.update_orders
+    }
+}
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, updates) =
+            parse::two_sections(input, "the rules, a blank line, then the updates")?;
+        let order_rules = rules
+            .into_iter()
+            .map(parse_rule)
+            .collect::<Result<_, _>>()?;
+        let update_orders = updates
+            .into_iter()
+            .map(parse_update)
+            .collect::<Result<_, _>>()?;
+        Ok(PrintQueue {
+            order_rules,
+            update_orders,
+        })
+    }
+
+    // Sum of the middle pages of the updates already in order
+    fn part1(queue: &Self::Input) -> Result<Answer> {
+        let mut correct_middle_total = 0;
+        for update_order in &queue.update_orders {
+            if is_good_order(update_order, &queue.order_rules) {
+                correct_middle_total += update_order[update_order.len() / 2];
+            }
+        }
+        Ok(correct_middle_total.into())
+    }
+
+    // Sum of the middle pages of the out of order updates once fixed
+    fn part2(queue: &Self::Input) -> Result<Answer> {
+        let mut incorrect_middle_total = 0;
+        for update_order in &queue.update_orders {
+            if !is_good_order(update_order, &queue.order_rules) {
+                let new_order = fix_update_order(update_order, &queue.order_rules);
+                incorrect_middle_total += new_order[new_order.len() / 2];
+            }
+        }
+        Ok(incorrect_middle_total.into())
+    }
+}
+
+// The rules, a blank line, then updates with a middle page
+impl Lint for Day5 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let (sections, mut problems) = lint::sections(input);
+        if sections.len() < 2 {
+            problems.push(ParseError::end_of_input(
+                input,
+                "the rules, a blank line, then the updates",
+            ));
+        }
+        for (i, section) in sections.iter().enumerate() {
+            for &line in section {
+                let problem = match i {
+                    0 => parse_rule(line).err(),
+                    1 => match parse_update(line) {
+                        Ok(pages) if pages.len() % 2 == 0 => {
+                            Some(line.error(line.text, "an odd number of pages"))
+                        }
+                        result => result.err(),
+                    },
+                    _ => Some(line.error(line.text, "end of input")),
+                };
+                problems.extend(problem);
+            }
+        }
+        problems
+    }
+}
+
+fn parse_rule(line: Line) -> Result<[u32; 2], ParseError> {
+    let (before, after) = line.split_once(line.text, "|")?;
+    Ok([line.number(before)?, line.number(after)?])
+}
+
+fn parse_update(line: Line) -> Result<Vec<u32>, ParseError> {
+    line.text.split(",").map(|s| line.number(s)).collect()
+}
+
+pub fn is_good_order(update_order: &[u32], rules: &[[u32; 2]]) -> bool {
+    for rule in rules {
+        let [before_val, after_val] = rule;
+        if update_order.contains(before_val) && update_order.contains(after_val) {
+            let before_index = update_order
+                .iter()
+                .position(|val| val == before_val)
+                .unwrap();
+            let after_index = update_order
+                .iter()
+                .position(|val| val == after_val)
+                .unwrap();
+            if before_index > after_index {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// Puts the pages in an order that follows every rule between them
+pub fn fix_update_order(update_order: &[u32], rules: &[[u32; 2]]) -> Vec<u32> {
+    let mut new_order = Vec::new();
+    for val in update_order {
+        if new_order.is_empty() {
+            new_order.push(*val);
+        } else {
+            let mut min_index_bound = 0;
+            for rule in rules {
+                let [before_val, after_val] = rule;
+                if val == after_val && new_order.contains(before_val) {
+                    let new_min_index = new_order.iter().position(|v| v == before_val).unwrap() + 1;
+                    if new_min_index > min_index_bound {
+                        min_index_bound = new_min_index;
+                    }
+                }
+            }
+            new_order.insert(min_index_bound, *val);
+        }
+    }
+
+    new_order
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day5::Day5;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day5>()?;
+
+    println!("Correct Middle Total: {}", Day5::part1(&input)?);
+    println!("Incorrect Middle Total: {}", Day5::part2(&input)?);
+
+    Ok(())
+}
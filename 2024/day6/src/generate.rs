@@ -0,0 +1,42 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Direction, Grid, GridPoint};
+
+use std::collections::HashSet;
+
+use crate::Day6;
+
+// size is the side of the square lab. Layouts where the guard walks in a loop without any
+// new obstruction are thrown away, since part 1 would never finish on them
+impl Generate for Day6 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(2);
+        loop {
+            let mut lab = Grid::filled((side, side), '.');
+            for point in lab.points().collect::<Vec<GridPoint>>() {
+                if rng.one_in(10) {
+                    lab.set(&point, '#');
+                }
+            }
+            let guard =
+                GridPoint::new(rng.below(side as u64) as i32, rng.below(side as u64) as i32);
+            lab.set(&guard, '^');
+            if guard_leaves(&lab, guard) {
+                return Generated::new(lab.to_string());
+            }
+        }
+    }
+}
+
+fn guard_leaves(lab: &Grid, mut position: GridPoint) -> bool {
+    let mut direction = Direction::Up;
+    let mut seen = HashSet::new();
+    while seen.insert((position, direction)) {
+        let next = position + direction.step();
+        match lab.get(&next) {
+            None => return true,
+            Some('#') => direction = direction.turn(),
+            Some(_) => position = next,
+        }
+    }
+    false
+}
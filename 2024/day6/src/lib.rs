@@ -0,0 +1,358 @@
+mod generate;
+
+use anyhow::{Error, Result};
+use common::{
+    lint, parallel, parse, repl, repl::Command, Animate, Answer, Frame, Frames, Inspect, Lint,
+    Params, ParseError, Solution,
+};
+use grid::{Direction, Grid, GridPoint};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_grid =
+            parse::char_grid(parse::lines(input), |ch| ".#^".contains(ch), "., # or ^")?;
+        let char_grid = Grid::new(input_grid);
+        if char_grid.find('^').is_none() {
+            return Err(ParseError::end_of_input(input, "the guard (^)").into());
+        }
+        Ok(char_grid)
+    }
+
+    fn part1(char_grid: &Self::Input) -> Result<Answer> {
+        Ok(count_visited_positions(char_grid).into())
+    }
+
+    fn part2(char_grid: &Self::Input) -> Result<Answer> {
+        Ok(count_loop_obstructions(char_grid).into())
+    }
+}
+
+impl Lint for Day6 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        let mut problems = lint::grid(parse::lines(input), |ch| ".#^".contains(ch), "., # or ^");
+        problems.extend(lint::markers(input, &[('^', "the guard (^)")]));
+        problems
+    }
+}
+
+impl Animate for Day6 {
+    // One frame per guard move or turn, with the visited tiles marked as part 1 counts them
+    fn frames(start_grid: &Self::Input) -> Frames<'_> {
+        let mut walk = Walk::new(start_grid);
+        let mut done = false;
+        Box::new(std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let frame = Frame::new(walk.picture(), walk.caption());
+            done = !walk.step();
+            Some(frame)
+        }))
+    }
+}
+
+impl Inspect for Day6 {
+    type State = Walk;
+
+    fn state(start_grid: &Self::Input) -> Walk {
+        Walk::new(start_grid)
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command {
+                name: "show",
+                usage: "show",
+                help: "draw the map with the visited tiles and the guard",
+                run: |_, walk, _| Ok(format!("{}{}", walk.picture(), walk.caption())),
+            },
+            Command {
+                name: "step",
+                usage: "step [N]",
+                help: "move or turn the guard N times, once by default",
+                run: |_, walk, args| {
+                    let count = repl::arg(args, 0, "a step count", Some(1))?;
+                    for _ in 0..count {
+                        if !walk.step() {
+                            return Ok(format!("{}, the guard has left", walk.caption()));
+                        }
+                    }
+                    Ok(walk.caption())
+                },
+            },
+            Command {
+                name: "guard",
+                usage: "guard",
+                help: "where the guard is and which way they face",
+                run: |_, walk, _| {
+                    Ok(format!(
+                        "row {}, column {}, facing {}",
+                        walk.position.index1,
+                        walk.position.index2,
+                        guard_arrow(walk.direction)
+                    ))
+                },
+            },
+            Command {
+                name: "cell",
+                usage: "cell ROW COL",
+                help: "what is on a tile, X once visited",
+                run: |_, walk, args| {
+                    let point = GridPoint::new(
+                        repl::arg(args, 0, "a row", None)?,
+                        repl::arg(args, 1, "a column", None)?,
+                    );
+                    walk.grid
+                        .get(&point)
+                        .map(String::from)
+                        .ok_or_else(|| Error::msg("Outside the map"))
+                },
+            },
+        ]
+    }
+}
+
+// The guard part way along their walk, the tiles they have been on marked with X
+pub struct Walk {
+    grid: Grid,
+    position: GridPoint,
+    direction: Direction,
+    steps: usize,
+}
+
+impl Walk {
+    fn new(start_grid: &Grid) -> Walk {
+        Walk {
+            grid: start_grid.clone(),
+            position: start_grid
+                .find('^')
+                .expect("Unable to find guard location!"),
+            direction: Direction::Up,
+            steps: 0,
+        }
+    }
+
+    // Moves or turns the guard once, false once they have walked off the map
+    fn step(&mut self) -> bool {
+        if !self.position.is_in_bounds(self.grid.get_bounds()) {
+            return false;
+        }
+        self.grid.set(&self.position, 'X');
+        let ahead = self.position + self.direction.step();
+        match self.grid.get(&ahead) {
+            Some('#') => self.direction = self.direction.turn(),
+            _ => self.position = ahead,
+        }
+        self.steps += 1;
+        self.position.is_in_bounds(self.grid.get_bounds())
+    }
+
+    fn picture(&self) -> String {
+        let mut grid = self.grid.clone();
+        grid.set(&self.position, guard_arrow(self.direction));
+        grid.to_string()
+    }
+
+    fn caption(&self) -> String {
+        let visited = self.grid.find_all('X').len();
+        let here = self.position.is_in_bounds(self.grid.get_bounds())
+            && self.grid.get(&self.position) != Some('X');
+        format!(
+            "step {}, {} positions visited",
+            self.steps,
+            visited + usize::from(here)
+        )
+    }
+}
+
+fn guard_arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+// Find all location the guard visits
+fn count_visited_positions(start_grid: &Grid) -> usize {
+    // Set initial conditions
+    let mut char_grid = start_grid.clone();
+    let mut guard_position = char_grid.find('^').expect("Unable to find guard location!");
+    let mut guard_direction = Direction::Up;
+
+    // Set the initial spot as visited
+    char_grid.set(&guard_position, 'X');
+    let mut next_char = char_grid.get(&(guard_position + guard_direction.step()));
+    while next_char.is_some() {
+        if next_char == Some('#') {
+            // check if obstruction is in front
+            // if so turn
+            guard_direction = guard_direction.turn();
+        } else {
+            // if no obstruction in front
+            // update guard position & set location as visited
+            guard_position = guard_position + guard_direction.step();
+            char_grid.set(&guard_position, 'X');
+        }
+        // update for looping
+        next_char = char_grid.get(&(guard_position + guard_direction.step()));
+    }
+
+    char_grid.find_all('X').len()
+}
+
+// Find all locations where an obstruction would create a loop
+// We only need to check locations where the guard will actually walk,
+// if he doesn't go somewhere normally, then adding an obstruction there does nothing
+fn count_loop_obstructions(static_char_grid: &Grid) -> usize {
+    let mut candidates = Vec::new();
+    let mut checked_positions = Vec::new();
+    let obstruction_locations = static_char_grid.find_all('#');
+    let mut guard_position = static_char_grid
+        .find('^')
+        .expect("Unable to find guard location!");
+    checked_positions.push(guard_position); // We know we cannot put one where the guard starts
+    let mut guard_direction = Direction::Up;
+
+    let mut next_char = static_char_grid.get(&(guard_position + guard_direction.step()));
+    while next_char.is_some() {
+        if next_char == Some('#') {
+            // movement logic
+            guard_direction = guard_direction.turn();
+        } else {
+            // if no obstruction in front, adding one here might make a loop
+            // but only the first time the guard walks into a location matters
+            let new_ob_location = guard_position + guard_direction.step();
+            if !checked_positions.contains(&new_ob_location) {
+                candidates.push((guard_position, guard_direction));
+                checked_positions.push(new_ob_location);
+            }
+            // update guard position and add to walked positions
+            guard_position = guard_position + guard_direction.step();
+        }
+        // update for looping
+        next_char = static_char_grid.get(&(guard_position + guard_direction.step()));
+    }
+
+    // Each candidate is tested on its own, so they can be spread over the cores
+    parallel::map(&candidates, |(position, direction)| {
+        test_new_obstruction(&obstruction_locations, position, *direction)
+    })
+    .into_iter()
+    .filter(|&loops| loops)
+    .count()
+}
+
+fn test_new_obstruction(
+    obstruction_location: &[GridPoint],
+    guard_location: &GridPoint,
+    current_direction: Direction,
+) -> bool {
+    // pretend there is an obstruction directly ahead add see if we create a loop
+    let mut next_location = *guard_location;
+    let mut next_dir = current_direction.turn();
+    let added_obstruction_location = *guard_location + current_direction.step();
+    let mut already_visited_obstructions = vec![(added_obstruction_location, current_direction)];
+
+    let mut obstruction_list = obstruction_location.to_vec();
+    obstruction_list.push(added_obstruction_location);
+    let mut next_ob =
+        find_next_obstruction_in_direction(&obstruction_list, &next_location, next_dir);
+    while next_ob.is_some() {
+        if already_visited_obstructions.contains(&(next_ob.unwrap(), next_dir)) {
+            already_visited_obstructions.push((next_ob.unwrap(), next_dir));
+            return true;
+        }
+        already_visited_obstructions.push((next_ob.unwrap(), next_dir));
+        next_location = next_ob.unwrap() + next_dir.opposite().step();
+        next_dir = next_dir.turn();
+        next_ob = find_next_obstruction_in_direction(&obstruction_list, &next_location, next_dir);
+    }
+    false
+}
+
+fn find_next_obstruction_in_direction(
+    obstruction_list: &[GridPoint],
+    starting_point: &GridPoint,
+    direction: Direction,
+) -> Option<GridPoint> {
+    match direction {
+        Direction::Up => {
+            let candidates = obstruction_list
+                .iter()
+                .filter(|&gp| {
+                    gp.index2 == starting_point.index2 && gp.index1 < starting_point.index1
+                })
+                .collect::<Vec<&GridPoint>>();
+            let mut closest_dist = i32::MAX;
+            let mut next_obstruction = None;
+            for gp in candidates {
+                let dist = starting_point.index1 - gp.index1;
+                if dist < closest_dist {
+                    closest_dist = dist;
+                    next_obstruction = Some(*gp);
+                }
+            }
+            next_obstruction
+        }
+        Direction::Right => {
+            let candidates = obstruction_list
+                .iter()
+                .filter(|&gp| {
+                    gp.index1 == starting_point.index1 && gp.index2 > starting_point.index2
+                })
+                .collect::<Vec<&GridPoint>>();
+            let mut closest_dist = i32::MAX;
+            let mut next_obstruction = None;
+            for gp in candidates {
+                let dist = gp.index2 - starting_point.index2;
+                if dist < closest_dist {
+                    closest_dist = dist;
+                    next_obstruction = Some(*gp);
+                }
+            }
+            next_obstruction
+        }
+        Direction::Down => {
+            let candidates = obstruction_list
+                .iter()
+                .filter(|&gp| {
+                    gp.index2 == starting_point.index2 && gp.index1 > starting_point.index1
+                })
+                .collect::<Vec<&GridPoint>>();
+            let mut closest_dist = i32::MAX;
+            let mut next_obstruction = None;
+            for gp in candidates {
+                let dist = gp.index1 - starting_point.index1;
+                if dist < closest_dist {
+                    closest_dist = dist;
+                    next_obstruction = Some(*gp);
+                }
+            }
+            next_obstruction
+        }
+        Direction::Left => {
+            let candidates = obstruction_list
+                .iter()
+                .filter(|&gp| {
+                    gp.index1 == starting_point.index1 && gp.index2 < starting_point.index2
+                })
+                .collect::<Vec<&GridPoint>>();
+            let mut closest_dist = i32::MAX;
+            let mut next_obstruction = None;
+            for gp in candidates {
+                let dist = starting_point.index2 - gp.index2;
+                if dist < closest_dist {
+                    closest_dist = dist;
+                    next_obstruction = Some(*gp);
+                }
+            }
+            next_obstruction
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use aoc2024_day6::Day6;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day6>()?;
+
+    println!("Number of Visited Positions: {}", Day6::part1(&input)?);
+    println!(
+        "Number of positions to add Obstruction: {}",
+        Day6::part2(&input)?
+    );
+
+    Ok(())
+}
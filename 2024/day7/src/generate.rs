@@ -0,0 +1,50 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day7;
+
+// Concatenating never makes more digits than the operands have between them, so keeping
+// each equation under this many digits keeps every result inside a u64
+const MAX_DIGITS: usize = 18;
+
+// size is the number of equations. Most are built from real operators, a few are off by
+// some amount so they cannot be made true
+impl Generate for Day7 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            let mut operands = Vec::new();
+            let mut digits = 0;
+            for _ in 0..rng.range(2..=12) {
+                let operand_digits = rng.range(1..=3) as usize;
+                if digits + operand_digits > MAX_DIGITS {
+                    break;
+                }
+                digits += operand_digits;
+                let low = 10_u64.pow(operand_digits as u32 - 1);
+                operands.push(low + rng.below(low * 9));
+            }
+            if operands.len() < 2 {
+                operands.push(rng.range(1..=9) as u64);
+            }
+
+            let mut test_value = operands[0];
+            for &operand in &operands[1..] {
+                test_value = match rng.below(3) {
+                    0 => test_value + operand,
+                    1 => test_value * operand,
+                    _ => format!("{}{}", test_value, operand).parse().unwrap(),
+                };
+            }
+            if rng.one_in(3) {
+                test_value += rng.range(1..=100) as u64;
+            }
+
+            let operands = operands
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>();
+            text += &format!("{}: {}\n", test_value, operands.join(" "));
+        }
+        Generated::new(text)
+    }
+}
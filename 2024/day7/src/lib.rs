@@ -0,0 +1,128 @@
+mod generate;
+
+use anyhow::Result;
+use common::{
+    lint, parallel,
+    parse::{self, Line},
+    Answer, Lint, Params, ParseError, Solution,
+};
+
+pub struct Day7;
+
+pub struct Equation {
+    test_value: u64,
+    operands: Vec<u64>,
+}
+
+impl Equation {
+    pub fn new(test_value: u64, operands: Vec<u64>) -> Equation {
+        Equation {
+            test_value,
+            operands,
+        }
+    }
+
+    pub fn test_value(&self) -> u64 {
+        self.test_value
+    }
+
+    pub fn operands(&self) -> &[u64] {
+        &self.operands
+    }
+}
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)
+            .map(parse_equation)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(equations: &Self::Input) -> Result<Answer> {
+        let total_calibration_number = calibrate(equations, equation_can_be_true);
+        Ok(total_calibration_number.into())
+    }
+
+    fn part2(equations: &Self::Input) -> Result<Answer> {
+        let total_calibration_number_with_concat =
+            calibrate(equations, equation_can_be_true_with_concat);
+        Ok(total_calibration_number_with_concat.into())
+    }
+}
+
+impl Lint for Day7 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::each_line(input, parse_equation)
+    }
+}
+
+fn parse_equation(line: Line) -> Result<Equation, ParseError> {
+    let (test_value_str, operands_str) = line.split_once(line.text, ":")?;
+    let test_value = line.number::<u64>(test_value_str)?;
+    let operands = operands_str
+        .split_whitespace()
+        .map(|x| line.number::<u64>(x))
+        .collect::<Result<Vec<u64>, _>>()?;
+    if operands.is_empty() {
+        return Err(line.error(line.end(), "an operand"));
+    }
+    Ok(Equation {
+        test_value,
+        operands,
+    })
+}
+
+// Sums the test values of the equations that can be true, checking them across the cores
+pub fn calibrate(equations: &[Equation], can_be_true: fn(u64, &[u64]) -> bool) -> u64 {
+    parallel::map(equations, |equation| {
+        if can_be_true(equation.test_value, &equation.operands) {
+            equation.test_value
+        } else {
+            0
+        }
+    })
+    .into_iter()
+    .sum()
+}
+
+// Whether + and * between the operands, evaluated left to right, can make the test value
+pub fn equation_can_be_true(test_value: u64, operands: &[u64]) -> bool {
+    let mut current_vals = Vec::new();
+    for &operand in operands {
+        if current_vals.is_empty() {
+            current_vals.push(operand);
+        } else {
+            current_vals = current_vals
+                .iter()
+                .flat_map(|x| vec![x + operand, x * operand])
+                .collect::<Vec<u64>>();
+        }
+    }
+    current_vals.contains(&test_value)
+}
+
+// The same with || as well, which joins the digits of two numbers
+pub fn equation_can_be_true_with_concat(test_value: u64, operands: &[u64]) -> bool {
+    let mut current_vals = Vec::new();
+    for &operand in operands {
+        if current_vals.is_empty() {
+            current_vals.push(operand);
+        } else {
+            current_vals = current_vals
+                .iter()
+                .flat_map(|x| {
+                    vec![
+                        x + operand,
+                        x * operand,
+                        (x.to_string() + operand.to_string().as_str())
+                            .parse::<u64>()
+                            .expect("Unable to parse after concatenation"),
+                    ]
+                })
+                .collect::<Vec<u64>>();
+        }
+    }
+    current_vals.contains(&test_value)
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use aoc2024_day7::Day7;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day7>()?;
+
+    println!("Total Calibration Number: {}", Day7::part1(&input)?);
+    println!(
+        "Total Calibration Number with concat: {}",
+        Day7::part2(&input)?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use common::{random::Rng, Generate, Generated};
+use grid::{Grid, GridPoint};
+
+use crate::Day8;
+
+const FREQUENCIES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+// size is the side of the square map, with about one frequency for every four columns
+impl Generate for Day8 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let side = size.max(2);
+        let mut map = Grid::filled((side, side), '.');
+        let mut frequencies = FREQUENCIES.to_vec();
+        rng.shuffle(&mut frequencies);
+        for &frequency in frequencies.iter().take(side.div_ceil(4)) {
+            for _ in 0..rng.range(2..=4) {
+                let point =
+                    GridPoint::new(rng.below(side as u64) as i32, rng.below(side as u64) as i32);
+                map.set(&point, frequency as char);
+            }
+        }
+        Generated::new(map.to_string())
+    }
+}
@@ -0,0 +1,121 @@
+mod generate;
+
+use anyhow::Result;
+use common::{lint, parse, Answer, Lint, Params, ParseError, Solution};
+use grid::{Grid, GridPoint};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_grid = parse::char_grid(
+            parse::lines(input),
+            |ch| ch == '.' || ch.is_ascii_alphanumeric(),
+            ". or an antenna frequency",
+        )?;
+        Ok(Grid::new(input_grid))
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        let bounds = map.get_bounds();
+        let mut antinode_locations = Vec::new();
+        for pair in find_antenna_pairs(map) {
+            let (antinode1, antinode2) = get_antinodes(pair);
+            if !antinode_locations.contains(&antinode1) && antinode1.is_in_bounds(bounds) {
+                antinode_locations.push(antinode1);
+            }
+            if !antinode_locations.contains(&antinode2) && antinode2.is_in_bounds(bounds) {
+                antinode_locations.push(antinode2);
+            }
+        }
+        Ok(antinode_locations.len().into())
+    }
+
+    // Find antinodes with harmonics
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        let bounds = map.get_bounds();
+        let mut antinode_locations_with_harmonics = Vec::new();
+        for pair in find_antenna_pairs(map) {
+            let antinodes_from_pair = get_antinodes_with_harmonics(pair, bounds);
+            for antinode in antinodes_from_pair {
+                if !antinode_locations_with_harmonics.contains(&antinode) {
+                    antinode_locations_with_harmonics.push(antinode);
+                }
+            }
+        }
+        Ok(antinode_locations_with_harmonics.len().into())
+    }
+}
+
+impl Lint for Day8 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::grid(
+            parse::lines(input),
+            |ch| ch == '.' || ch.is_ascii_alphanumeric(),
+            ". or an antenna frequency",
+        )
+    }
+}
+
+fn find_antenna_pairs(map: &Grid) -> Vec<(GridPoint, GridPoint)> {
+    let frequency_types = find_unique_frequencies(map);
+
+    let mut antenna_pairs = Vec::new();
+    for frequency in frequency_types {
+        let frequency_locations = map.find_all(frequency);
+        for f1 in frequency_locations.clone() {
+            for f2 in frequency_locations.clone() {
+                if f1 != f2 {
+                    antenna_pairs.push((f1, f2));
+                }
+            }
+        }
+    }
+
+    antenna_pairs
+}
+
+fn find_unique_frequencies(grid: &Grid) -> Vec<char> {
+    let mut frequencies = Vec::new();
+    let (index1_max, index2_max) = grid.get_bounds();
+    for i in 0..index1_max {
+        for j in 0..index2_max {
+            if let Some(next_char) = grid.get(&GridPoint::new(i as i32, j as i32)) {
+                if next_char != '.' && !frequencies.contains(&next_char) {
+                    frequencies.push(next_char);
+                }
+            }
+        }
+    }
+    frequencies
+}
+
+fn get_antinodes(pair: (GridPoint, GridPoint)) -> (GridPoint, GridPoint) {
+    let (node1, node2) = pair;
+    let gap = node2 - node1;
+    (node2 + gap, node1 - gap)
+}
+
+fn get_antinodes_with_harmonics(
+    pair: (GridPoint, GridPoint),
+    bounds: (usize, usize),
+) -> Vec<GridPoint> {
+    let (node1, node2) = pair;
+    let mut antinodes = Vec::new();
+    let gap = node2 - node1;
+
+    let mut add_gap_node = node2;
+    while add_gap_node.is_in_bounds(bounds) {
+        antinodes.push(add_gap_node);
+        add_gap_node = add_gap_node + gap;
+    }
+    let mut sub_gap_node = node1;
+    while sub_gap_node.is_in_bounds(bounds) {
+        antinodes.push(sub_gap_node);
+        sub_gap_node = sub_gap_node - gap;
+    }
+
+    antinodes
+}
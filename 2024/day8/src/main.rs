@@ -0,0 +1,15 @@
+use anyhow::Result;
+use aoc2024_day8::Day8;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day8>()?;
+
+    println!("Antinode locations: {}", Day8::part1(&input)?);
+    println!(
+        "Antinode locations with harmonics: {}",
+        Day8::part2(&input)?
+    );
+
+    Ok(())
+}
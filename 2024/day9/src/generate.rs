@@ -0,0 +1,23 @@
+use common::{random::Rng, Generate, Generated};
+
+use crate::Day9;
+
+// size is the number of digits in the disk map, files get 1 to 9 blocks and the gaps
+// between them 0 to 9
+impl Generate for Day9 {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        // An odd count, so the map starts and ends with a file
+        let digits = size.max(1) | 1;
+        let mut disk_map = String::new();
+        for i in 0..digits {
+            let blocks = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
+            disk_map += &blocks.to_string();
+        }
+        disk_map.push('\n');
+        Generated::new(disk_map)
+    }
+}
@@ -1,38 +1,58 @@
+mod generate;
+
 use anyhow::Result;
+use common::{lint, parse, Answer, Lint, Params, ParseError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        for line in parse::lines(input) {
+            line.checked_chars(|ch| ch.is_ascii_digit(), "a block size digit")?;
+        }
+        Ok(input.to_string())
+    }
+
+    fn part1(disk_map: &Self::Input) -> Result<Answer> {
+        Ok(compact_blocks(disk_map).into())
+    }
 
-use std::{
-    fs,
-    io::{BufReader, Read},
-};
+    fn part2(disk_map: &Self::Input) -> Result<Answer> {
+        Ok(compact_files(disk_map).into())
+    }
+}
 
-fn main() -> Result<()> {
-    // Read Input
-    // let input_file = fs::File::open("puzzle_input_example.txt")?;
-    let input_file = fs::File::open("puzzle_input.txt")?;
-    let mut disk_map = String::new();
-    let _ = BufReader::new(input_file).read_to_string(&mut disk_map)?;
+impl Lint for Day9 {
+    fn lint(input: &str, _params: &Params) -> Vec<ParseError> {
+        lint::each_line(input, |line| {
+            line.checked_chars(|ch| ch.is_ascii_digit(), "a block size digit")
+        })
+    }
+}
 
-    // First Compression Method
-    let mut blocks = convert_disk_map_to_blocks(disk_map.clone());
+// First Compression Method
+fn compact_blocks(disk_map: &str) -> usize {
+    let mut blocks = convert_disk_map_to_blocks(disk_map);
     let mut first_space = blocks.iter().position(|x| x.is_empty());
     let mut last_file = blocks.iter().rposition(|x| !x.is_empty());
     while first_space.get_or_insert(blocks.len()) < last_file.get_or_insert(0) {
         // swap blocks
         if let Some(block_position1) = first_space {
             if let Some(block_position2) = last_file {
-                let val1 = blocks[block_position1].clone();
-                blocks[block_position1] = blocks[block_position2].clone();
-                blocks[block_position2] = val1;
+                blocks.swap(block_position1, block_position2);
             }
         }
         // update while loop parameters
         first_space = blocks.iter().position(|x| x.is_empty());
         last_file = blocks.iter().rposition(|x| !x.is_empty());
     }
-    let checksum = compute_checksum(&blocks);
-    println!("First Method Checksum: {}", checksum);
+    compute_checksum(&blocks)
+}
 
-    // Second Compression Method
+// Second Compression Method
+fn compact_files(disk_map: &str) -> usize {
     let mut file_system = convert_disk_map_to_files(disk_map);
     let reverse_files = file_system
         .clone()
@@ -48,7 +68,6 @@ fn main() -> Result<()> {
             .position(|f| f == &file)
             .expect("Could not find file in filesystem");
         if let Some(first_open_space_with_size) = file_system
-            .clone()
             .iter()
             .position(|f| f.is_empty() && f.size >= file_size)
         {
@@ -72,13 +91,10 @@ fn main() -> Result<()> {
         }
     }
     let blocks2 = convert_filesystem_to_blocks(&file_system);
-    let checksum2 = compute_checksum(&blocks2);
-    println!("Second Method Checksum: {}", checksum2);
-
-    Ok(())
+    compute_checksum(&blocks2)
 }
 
-fn convert_disk_map_to_blocks(disk_map: String) -> Vec<Block> {
+fn convert_disk_map_to_blocks(disk_map: &str) -> Vec<Block> {
     let mut is_file = true;
     let mut current_file_id = 0;
     let mut blocks: Vec<Block> = Vec::new();
@@ -107,7 +123,7 @@ fn convert_disk_map_to_blocks(disk_map: String) -> Vec<Block> {
     blocks
 }
 
-fn convert_disk_map_to_files(disk_map: String) -> Vec<File> {
+fn convert_disk_map_to_files(disk_map: &str) -> Vec<File> {
     let mut is_file = true;
     let mut current_file_id = 0;
     let mut files: Vec<File> = Vec::new();
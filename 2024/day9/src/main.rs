@@ -0,0 +1,12 @@
+use anyhow::Result;
+use aoc2024_day9::Day9;
+use common::{input, Solution};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day9>()?;
+
+    println!("First Method Checksum: {}", Day9::part1(&input)?);
+    println!("Second Method Checksum: {}", Day9::part2(&input)?);
+
+    Ok(())
+}
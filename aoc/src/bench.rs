@@ -0,0 +1,191 @@
+use anyhow::{Error, Result};
+use common::Params;
+use std::{collections::BTreeMap, fs, path::Path, time::Duration};
+
+use crate::{days::Day, ledger, stage, DayInput};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort_unstable();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+// Times every stage of a day, throwing away the warm-up runs. Each run parses afresh,
+// and parts that were not asked for come back as None
+pub fn bench_day(
+    day: &Day,
+    input: &DayInput,
+    parts: [bool; 2],
+    warmup: usize,
+    runs: usize,
+) -> Result<[Option<Stats>; 3]> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for run in 0..warmup + runs {
+        let mut elapsed = [Duration::ZERO; 3];
+        let parsed = stage::timed(&mut elapsed[0], || {
+            day.solution.parse_dyn(&input.text, &input.params)
+        })?;
+        if parts[0] {
+            stage::timed(&mut elapsed[1], || day.solution.part1_dyn(parsed.as_ref()))?;
+        }
+        if parts[1] {
+            stage::timed(&mut elapsed[2], || day.solution.part2_dyn(parsed.as_ref()))?;
+        }
+        if run >= warmup {
+            for (stage_samples, time) in samples.iter_mut().zip(elapsed) {
+                stage_samples.push(time);
+            }
+        }
+    }
+
+    let wanted = [true, parts[0], parts[1]];
+    let mut stats = [None; 3];
+    for ((stat, stage_samples), wanted) in stats.iter_mut().zip(samples).zip(wanted) {
+        if wanted {
+            *stat = Some(Stats::from_samples(stage_samples));
+        }
+    }
+    Ok(stats)
+}
+
+// Median times in milliseconds keyed by day, stage and input, e.g.
+// "day16.part2.3f2a9c0d1e4b5a67 = 4.512". An example input or a different --set is timed
+// apart from the real input, and never compared with or overwrites its medians
+#[derive(Default)]
+pub struct Baseline {
+    medians: BTreeMap<String, f64>,
+}
+
+impl Baseline {
+    // A missing file is an empty baseline, so the first run has nothing to compare to
+    pub fn load(path: &Path) -> Result<Baseline> {
+        let mut baseline = Baseline::default();
+        if !path.exists() {
+            return Ok(baseline);
+        }
+        let read_error = |e| Error::msg(format!("{}: {}", path.display(), e));
+        let params = fs::read_to_string(path)
+            .map_err(|e| read_error(Error::from(e)))?
+            .parse::<Params>()
+            .map_err(read_error)?;
+        for (key, value) in params.iter() {
+            let millis = value
+                .parse()
+                .map_err(|_| read_error(Error::msg(format!("{} is not a time", key))))?;
+            baseline.medians.insert(key.to_string(), millis);
+        }
+        Ok(baseline)
+    }
+
+    pub fn get(&self, day: u8, stage: &str, input: &str) -> Option<Duration> {
+        self.medians
+            .get(&key(day, stage, input))
+            .map(|millis| Duration::from_secs_f64(millis / 1000.0))
+    }
+
+    pub fn record(&mut self, day: u8, stage: &str, input: &str, median: Duration) {
+        self.medians
+            .insert(key(day, stage, input), median.as_secs_f64() * 1000.0);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut text = String::new();
+        for (key, millis) in &self.medians {
+            text += &format!("{} = {:.6}\n", key, millis);
+        }
+        fs::write(path, text).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+}
+
+fn key(day: u8, stage: &str, input: &str) -> String {
+    format!("day{}.{}.{}", day, stage, input)
+}
+
+// Identifies the input text together with the params it ran with
+pub fn input_key(input: &DayInput) -> String {
+    let mut text = input.text.clone();
+    for (key, value) in input.params.iter() {
+        text += &format!("\n{} = {}", key, value);
+    }
+    ledger::input_hash(&text)
+}
+
+// Generated inputs keep their own baseline, their times mean nothing next to the real input's
+pub fn default_file(generate: Option<usize>, seed: u64) -> String {
+    match generate {
+        Some(size) => format!("bench_baseline_size{}_seed{}.txt", size, seed),
+        None => "bench_baseline.txt".to_string(),
+    }
+}
+
+// Percent change from the baseline, positive when slower. None for a zero baseline, which
+// nothing can be a percentage of
+pub fn change(current: Duration, baseline: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_take_the_middle_sample() {
+        let samples = [5, 1, 3, 4, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn baseline_round_trips_through_its_file() {
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}.txt", std::process::id()));
+        let mut baseline = Baseline::default();
+        baseline.record(16, "part2", "abc", Duration::from_micros(4512));
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            loaded.get(16, "part2", "abc"),
+            Some(Duration::from_micros(4512))
+        );
+        assert_eq!(loaded.get(16, "part2", "def"), None);
+        assert_eq!(loaded.get(16, "part1", "abc"), None);
+    }
+
+    #[test]
+    fn params_change_the_input_key() {
+        let mut input = DayInput {
+            path: "example.txt".into(),
+            text: "1 2\n".to_string(),
+            params: Params::new(),
+        };
+        let plain = input_key(&input);
+        input.params.set("strategy", "counted");
+        assert_ne!(input_key(&input), plain);
+    }
+
+    #[test]
+    fn a_zero_baseline_has_no_change() {
+        let millis = Duration::from_millis;
+        assert_eq!(change(millis(3), millis(2)), Some(50.0));
+        assert_eq!(change(millis(3), Duration::ZERO), None);
+    }
+}
@@ -0,0 +1,440 @@
+use anyhow::{Error, Result};
+use common::{Answer, Palette, Params};
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use crate::ledger::Feedback;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc [--year <YEAR>] <COMMAND>, where the latest year is used without --year, and COMMAND is:
+    aoc run --day <N> [--part <1|2>] [--input <PATH|-> | --generate <SIZE> [--seed <N>]]
+            [--set <KEY=VALUE>]... [--format <table|json>] [--jobs <N>] [--profile-alloc]
+    aoc run --all [--generate <SIZE> [--seed <N>]] [--set <KEY=VALUE>]... [--format <table|json>]
+            [--jobs <N>] [--profile-alloc]
+    aoc bench (--day <N> | --all) [--warmup <N>] [--runs <N>] [--baseline <PATH>] [--save]
+              [--threshold <PERCENT>] [--part <1|2>] [--input <PATH|-> | --generate <SIZE>]
+              [--seed <N>] [--set <KEY=VALUE>]... [--jobs <N>]
+    aoc animate --day <N> [--input <PATH|-> | --generate <SIZE> [--seed <N>]]
+                [--set <KEY=VALUE>]... [--fps <N>]
+    aoc repl --day <N> [--input <PATH|-> | --generate <SIZE> [--seed <N>]] [--set <KEY=VALUE>]...
+    aoc image --day <N> --output <PATH.ppm|PATH.pgm> [--scale <N>] [--palette <C=RRGGBB>]...
+              [--frames [--skip <N>] [--take <N>]] [--input <PATH|-> | --generate <SIZE>]
+              [--seed <N>] [--set <KEY=VALUE>]...
+    aoc generate --day <N> [--size <SIZE>] [--seed <N>] [--output <PATH>]
+    aoc examples [--day <N>]
+    aoc lint [--day <N> [<PATH|->]]
+    aoc new --day <N>
+    aoc answer --day <N> --part <1|2> (--correct | --too-high | --too-low | --wrong) <ANSWER>
+               [--input <PATH|->]
+    aoc verify [--day <N>]
+    aoc list";
+
+pub enum Command {
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Animate(AnimateOptions),
+    Repl(RunOptions),
+    Image(ImageOptions),
+    Generate(GenerateOptions),
+    Examples(Option<u8>),
+    Lint(LintOptions),
+    New(u8),
+    Answer(AnswerOptions),
+    Verify(Option<u8>),
+    List,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+}
+
+#[derive(Default)]
+pub struct RunOptions {
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub all: bool,
+    // Applied over the params file of every input that runs
+    pub set: Params,
+    pub format: Format,
+    // Solve a random input of this size instead of reading one
+    pub generate: Option<usize>,
+    pub seed: u64,
+    // Threads for running days side by side and inside the solvers, every core if unset
+    pub jobs: Option<NonZeroUsize>,
+    // Count each stage's allocations, which runs the days one after another
+    pub profile_alloc: bool,
+}
+
+pub struct BenchOptions {
+    pub run: RunOptions,
+    pub warmup: usize,
+    pub runs: usize,
+    // Median times per day and stage, compared against and optionally overwritten. The
+    // year's own file when unset
+    pub baseline: Option<PathBuf>,
+    pub save: bool,
+    // How many percent slower than the baseline a stage may get before it is flagged
+    pub threshold: f64,
+}
+
+pub struct AnimateOptions {
+    pub run: RunOptions,
+    pub fps: f64,
+}
+
+pub struct ImageOptions {
+    pub run: RunOptions,
+    // With --frames, the numbered frames are written next to this path
+    pub output: PathBuf,
+    pub scale: usize,
+    pub palette: Palette,
+    pub frames: bool,
+    pub skip: usize,
+    pub take: Option<usize>,
+}
+
+pub struct GenerateOptions {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    // Also gets a .params file next to it when the input needs params
+    pub output: Option<PathBuf>,
+}
+
+pub struct LintOptions {
+    pub day: Option<u8>,
+    pub input: Option<PathBuf>,
+}
+
+// What the puzzle site said about an answer for one of our inputs
+pub struct AnswerOptions {
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<PathBuf>,
+    pub answer: Answer,
+    pub feedback: Feedback,
+}
+
+// --year may come anywhere, every command works within that one year
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<(Option<u16>, Command)> {
+    let mut args = args.into_iter().collect::<Vec<String>>();
+    let year = match args.iter().position(|arg| arg == "--year") {
+        Some(index) => {
+            let value = flag_value("--year", args.get(index + 1).cloned())?;
+            args.drain(index..index + 2);
+            Some(value.parse()?)
+        }
+        None => None,
+    };
+
+    let mut args = args.into_iter();
+    let command = args.next().ok_or_else(|| Error::msg(USAGE))?;
+    let command = match command.as_str() {
+        "run" => parse_run(args).map(Command::Run),
+        "bench" => parse_bench(args).map(Command::Bench),
+        "animate" => parse_animate(args).map(Command::Animate),
+        "repl" => parse_repl(args).map(Command::Repl),
+        "image" => parse_image(args).map(Command::Image),
+        "generate" => parse_generate(args).map(Command::Generate),
+        "examples" => parse_day(args).map(Command::Examples),
+        "lint" => parse_lint(args).map(Command::Lint),
+        "new" => parse_day(args)?
+            .map(Command::New)
+            .ok_or_else(|| Error::msg(format!("Expected --day\n\n{}", USAGE))),
+        "answer" => parse_answer(args).map(Command::Answer),
+        "verify" => parse_day(args).map(Command::Verify),
+        "list" => Ok(Command::List),
+        _ => Err(Error::msg(format!(
+            "Unknown command: {}\n\n{}",
+            command, USAGE
+        ))),
+    }?;
+    Ok((year, command))
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunOptions> {
+    let mut options = RunOptions::default();
+    while let Some(flag) = args.next() {
+        if flag == "--format" {
+            options.format = match flag_value(&flag, args.next())?.as_str() {
+                "table" => Format::Table,
+                "json" => Format::Json,
+                other => return Err(Error::msg(format!("Unknown format: {}", other))),
+            };
+        } else if flag == "--profile-alloc" {
+            options.profile_alloc = true;
+        } else if !parse_run_flag(&mut options, &flag, &mut args)? {
+            return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE)));
+        }
+    }
+    check_run(options)
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchOptions> {
+    let mut run = RunOptions::default();
+    let mut options = BenchOptions {
+        run: RunOptions::default(),
+        warmup: 1,
+        runs: 10,
+        baseline: None,
+        save: false,
+        threshold: 10.0,
+    };
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--warmup" => options.warmup = flag_value(&flag, args.next())?.parse()?,
+            "--runs" => options.runs = flag_value(&flag, args.next())?.parse()?,
+            "--baseline" => options.baseline = Some(PathBuf::from(flag_value(&flag, args.next())?)),
+            "--save" => options.save = true,
+            "--threshold" => options.threshold = flag_value(&flag, args.next())?.parse()?,
+            _ => {
+                if !parse_run_flag(&mut run, &flag, &mut args)? {
+                    return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE)));
+                }
+            }
+        }
+    }
+    if options.runs == 0 {
+        return Err(Error::msg("--runs must be at least 1"));
+    }
+    options.run = check_run(run)?;
+    Ok(options)
+}
+
+fn parse_animate(mut args: impl Iterator<Item = String>) -> Result<AnimateOptions> {
+    let mut run = RunOptions::default();
+    let mut fps = 10.0;
+    while let Some(flag) = args.next() {
+        if flag == "--fps" {
+            fps = flag_value(&flag, args.next())?.parse()?;
+        } else if !parse_run_flag(&mut run, &flag, &mut args)? {
+            return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE)));
+        }
+    }
+    if fps <= 0.0 {
+        return Err(Error::msg("--fps must be above 0"));
+    }
+    if run.all || run.part.is_some() {
+        return Err(Error::msg(
+            "animate plays a single --day and takes no --part",
+        ));
+    }
+    Ok(AnimateOptions {
+        run: check_run(run)?,
+        fps,
+    })
+}
+
+fn parse_repl(mut args: impl Iterator<Item = String>) -> Result<RunOptions> {
+    let mut run = RunOptions::default();
+    while let Some(flag) = args.next() {
+        if !parse_run_flag(&mut run, &flag, &mut args)? {
+            return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE)));
+        }
+    }
+    if run.all || run.part.is_some() {
+        return Err(Error::msg("repl opens a single --day and takes no --part"));
+    }
+    check_run(run)
+}
+
+fn parse_image(mut args: impl Iterator<Item = String>) -> Result<ImageOptions> {
+    let mut run = RunOptions::default();
+    let mut output = None;
+    let mut options = ImageOptions {
+        run: RunOptions::default(),
+        output: PathBuf::new(),
+        scale: 1,
+        palette: Palette::default(),
+        frames: false,
+        skip: 0,
+        take: None,
+    };
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--output" => output = Some(PathBuf::from(flag_value(&flag, args.next())?)),
+            "--scale" => options.scale = flag_value(&flag, args.next())?.parse()?,
+            "--palette" => options
+                .palette
+                .set_entry(&flag_value(&flag, args.next())?)?,
+            "--frames" => options.frames = true,
+            "--skip" => options.skip = flag_value(&flag, args.next())?.parse()?,
+            "--take" => options.take = Some(flag_value(&flag, args.next())?.parse()?),
+            _ => {
+                if !parse_run_flag(&mut run, &flag, &mut args)? {
+                    return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE)));
+                }
+            }
+        }
+    }
+    if options.scale == 0 {
+        return Err(Error::msg("--scale must be at least 1"));
+    }
+    if !options.frames && (options.skip > 0 || options.take.is_some()) {
+        return Err(Error::msg("--skip and --take only apply to --frames"));
+    }
+    if run.all || run.part.is_some() {
+        return Err(Error::msg("image draws a single --day and takes no --part"));
+    }
+    options.output = output.ok_or_else(|| Error::msg(format!("Expected --output\n\n{}", USAGE)))?;
+    options.run = check_run(run)?;
+    Ok(options)
+}
+
+// Handles the flags that pick what to run, false if the flag is not one of them
+fn parse_run_flag(
+    options: &mut RunOptions,
+    flag: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<bool> {
+    match flag {
+        "--all" => options.all = true,
+        "--day" => options.day = Some(flag_value(flag, args.next())?.parse()?),
+        "--part" => {
+            let part = flag_value(flag, args.next())?.parse()?;
+            if part != 1 && part != 2 {
+                return Err(Error::msg("--part must be 1 or 2"));
+            }
+            options.part = Some(part);
+        }
+        "--input" => options.input = Some(PathBuf::from(flag_value(flag, args.next())?)),
+        "--generate" => options.generate = Some(flag_value(flag, args.next())?.parse()?),
+        "--seed" => options.seed = flag_value(flag, args.next())?.parse()?,
+        "--jobs" => {
+            let jobs = flag_value(flag, args.next())?.parse::<usize>()?;
+            options.jobs = Some(
+                NonZeroUsize::new(jobs).ok_or_else(|| Error::msg("--jobs must be at least 1"))?,
+            );
+        }
+        "--set" => {
+            let assignment = flag_value(flag, args.next())?;
+            let (key, value) = assignment.split_once('=').ok_or_else(|| {
+                Error::msg(format!("--set expects KEY=VALUE, got {}", assignment))
+            })?;
+            options.set.set(key.trim(), value.trim());
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn check_run(options: RunOptions) -> Result<RunOptions> {
+    if options.all == options.day.is_some() {
+        return Err(Error::msg(format!(
+            "Expected exactly one of --day or --all\n\n{}",
+            USAGE
+        )));
+    }
+    if options.all && options.input.is_some() {
+        return Err(Error::msg("--input can only be used with a single --day"));
+    }
+    if options.input.is_some() && options.generate.is_some() {
+        return Err(Error::msg("Expected at most one of --input or --generate"));
+    }
+    Ok(options)
+}
+
+fn parse_answer(mut args: impl Iterator<Item = String>) -> Result<AnswerOptions> {
+    let (mut day, mut part, mut input, mut verdict) = (None, None, None, None);
+    while let Some(flag) = args.next() {
+        let feedback = match flag.as_str() {
+            "--day" => {
+                day = Some(flag_value(&flag, args.next())?.parse()?);
+                continue;
+            }
+            "--part" => {
+                part = Some(flag_value(&flag, args.next())?.parse()?);
+                continue;
+            }
+            "--input" => {
+                input = Some(PathBuf::from(flag_value(&flag, args.next())?));
+                continue;
+            }
+            "--correct" => Feedback::Correct,
+            "--too-high" => Feedback::TooHigh,
+            "--too-low" => Feedback::TooLow,
+            "--wrong" => Feedback::Wrong,
+            _ => return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE))),
+        };
+        if verdict.is_some() {
+            return Err(Error::msg("Expected one answer at a time"));
+        }
+        verdict = Some((flag_value(&flag, args.next())?, feedback));
+    }
+
+    let missing = |what| Error::msg(format!("Expected {}\n\n{}", what, USAGE));
+    let part = part.ok_or_else(|| missing("--part"))?;
+    if part != 1 && part != 2 {
+        return Err(Error::msg("--part must be 1 or 2"));
+    }
+    let (answer, feedback) =
+        verdict.ok_or_else(|| missing("--correct, --too-high, --too-low or --wrong"))?;
+    Ok(AnswerOptions {
+        day: day.ok_or_else(|| missing("--day"))?,
+        part,
+        input,
+        answer: answer.trim().parse()?,
+        feedback,
+    })
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<GenerateOptions> {
+    let mut day = None;
+    let mut options = GenerateOptions {
+        day: 0,
+        size: 10,
+        seed: 0,
+        output: None,
+    };
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--day" => day = Some(flag_value(&flag, args.next())?.parse()?),
+            "--size" => options.size = flag_value(&flag, args.next())?.parse()?,
+            "--seed" => options.seed = flag_value(&flag, args.next())?.parse()?,
+            "--output" => options.output = Some(PathBuf::from(flag_value(&flag, args.next())?)),
+            _ => return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE))),
+        }
+    }
+    options.day = day.ok_or_else(|| Error::msg(format!("Expected --day\n\n{}", USAGE)))?;
+    Ok(options)
+}
+
+fn parse_lint(mut args: impl Iterator<Item = String>) -> Result<LintOptions> {
+    let mut options = LintOptions {
+        day: None,
+        input: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => options.day = Some(flag_value(&arg, args.next())?.parse()?),
+            _ if arg.starts_with("--") => {
+                return Err(Error::msg(format!("Unknown flag: {}\n\n{}", arg, USAGE)))
+            }
+            _ if options.input.is_none() => options.input = Some(PathBuf::from(arg)),
+            _ => return Err(Error::msg(format!("Expected one input, got {} too", arg))),
+        }
+    }
+    if options.input.is_some() && options.day.is_none() {
+        return Err(Error::msg("An input path needs a --day to lint it against"));
+    }
+    Ok(options)
+}
+
+fn parse_day(mut args: impl Iterator<Item = String>) -> Result<Option<u8>> {
+    let mut day = None;
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--day" => day = Some(flag_value(&flag, args.next())?.parse()?),
+            _ => return Err(Error::msg(format!("Unknown flag: {}\n\n{}", flag, USAGE))),
+        }
+    }
+    Ok(day)
+}
+
+fn flag_value(flag: &str, value: Option<String>) -> Result<String> {
+    value.ok_or_else(|| Error::msg(format!("{} expects a value", flag)))
+}
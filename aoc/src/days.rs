@@ -0,0 +1,194 @@
+use anyhow::{Error, Result};
+use common::{
+    random::Rng, DynAnimate, DynInspect, DynPicture, DynSolution, Generate, Generated, Lint,
+    Params, ParseError,
+};
+use std::path::{Path, PathBuf};
+
+// One Advent of Code event, whose days live in a directory named after it
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+}
+
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    pub solution: &'static dyn DynSolution,
+    pub generate: fn(&mut Rng, usize) -> Generated,
+    pub lint: fn(&str, &Params) -> Vec<ParseError>,
+    pub animation: Option<&'static dyn DynAnimate>,
+    pub picture: Option<&'static dyn DynPicture>,
+    pub repl: Option<&'static dyn DynInspect>,
+}
+
+// The latest one is used when no --year is given
+pub const YEARS: [Year; 1] = [Year::new(2024, &DAYS_2024)];
+
+const DAYS_2024: [Day; 19] = [
+    Day::new(2024, 1, &aoc2024_day1::Day1),
+    Day::new(2024, 2, &aoc2024_day2::Day2),
+    Day::new(2024, 3, &aoc2024_day3::Day3),
+    Day::new(2024, 4, &aoc2024_day4::Day4),
+    Day::new(2024, 5, &aoc2024_day5::Day5),
+    Day::new(2024, 6, &aoc2024_day6::Day6)
+        .with_animation(&aoc2024_day6::Day6)
+        .with_repl(&aoc2024_day6::Day6),
+    Day::new(2024, 7, &aoc2024_day7::Day7),
+    Day::new(2024, 8, &aoc2024_day8::Day8),
+    Day::new(2024, 9, &aoc2024_day9::Day9),
+    Day::new(2024, 10, &aoc2024_day10::Day10),
+    Day::new(2024, 11, &aoc2024_day11::Day11),
+    Day::new(2024, 12, &aoc2024_day12::Day12)
+        .with_picture(&aoc2024_day12::Day12)
+        .with_repl(&aoc2024_day12::Day12),
+    Day::new(2024, 13, &aoc2024_day13::Day13),
+    Day::new(2024, 14, &aoc2024_day14::Day14)
+        .with_animation(&aoc2024_day14::Day14)
+        .with_picture(&aoc2024_day14::Day14)
+        .with_repl(&aoc2024_day14::Day14),
+    Day::new(2024, 15, &aoc2024_day15::Day15)
+        .with_animation(&aoc2024_day15::Day15)
+        .with_repl(&aoc2024_day15::Day15),
+    Day::new(2024, 16, &aoc2024_day16::Day16).with_animation(&aoc2024_day16::Day16),
+    Day::new(2024, 17, &aoc2024_day17::Day17).with_repl(&aoc2024_day17::Day17),
+    Day::new(2024, 18, &aoc2024_day18::Day18).with_picture(&aoc2024_day18::Day18),
+    Day::new(2024, 19, &aoc2024_day19::Day19),
+];
+
+// The workspace root, which holds every day's directory
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."))
+        .to_path_buf()
+}
+
+// The given year, or the latest one
+pub fn find_year(number: Option<u16>) -> Result<&'static Year> {
+    match number {
+        Some(number) => YEARS
+            .iter()
+            .find(|year| year.number == number)
+            .ok_or_else(|| Error::msg(format!("No solvers for {}", number))),
+        None => Ok(YEARS
+            .iter()
+            .max_by_key(|year| year.number)
+            .expect("there is always a year")),
+    }
+}
+
+// Every day of every year
+#[cfg(test)]
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|year| year.days)
+}
+
+impl Year {
+    const fn new(number: u16, days: &'static [Day]) -> Year {
+        Year { number, days }
+    }
+
+    pub fn directory(&self) -> PathBuf {
+        workspace_dir().join(self.number.to_string())
+    }
+
+    pub fn find(&self, number: u8) -> Option<&'static Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+}
+
+impl Day {
+    const fn new<S: DynSolution + Generate + Lint>(
+        year: u16,
+        number: u8,
+        solution: &'static S,
+    ) -> Day {
+        Day {
+            year,
+            number,
+            solution,
+            generate: S::generate,
+            lint: S::lint,
+            animation: None,
+            picture: None,
+            repl: None,
+        }
+    }
+
+    const fn with_animation(self, animation: &'static dyn DynAnimate) -> Day {
+        Day {
+            animation: Some(animation),
+            ..self
+        }
+    }
+
+    const fn with_picture(self, picture: &'static dyn DynPicture) -> Day {
+        Day {
+            picture: Some(picture),
+            ..self
+        }
+    }
+
+    const fn with_repl(self, repl: &'static dyn DynInspect) -> Day {
+        Day {
+            repl: Some(repl),
+            ..self
+        }
+    }
+
+    pub fn directory(&self) -> PathBuf {
+        workspace_dir()
+            .join(self.year.to_string())
+            .join(format!("day{}", self.number))
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        self.directory().join("puzzle_input.txt")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_generated_input_solves() {
+        for day in all() {
+            for seed in 0..3 {
+                let generated = (day.generate)(&mut Rng::new(seed), 8);
+                let solved = day
+                    .solution
+                    .parse_dyn(&generated.text, &generated.params)
+                    .and_then(|parsed| {
+                        day.solution.part1_dyn(parsed.as_ref())?;
+                        day.solution.part2_dyn(parsed.as_ref())
+                    });
+                if let Err(e) = solved {
+                    panic!(
+                        "{} day {} seed {}: {}\n{}",
+                        day.year, day.number, seed, e, generated.text
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_generated_input_lints_clean() {
+        for day in all() {
+            for seed in 0..3 {
+                let generated = (day.generate)(&mut Rng::new(seed), 8);
+                let problems = (day.lint)(&generated.text, &generated.params);
+                assert!(
+                    problems.is_empty(),
+                    "day {} seed {}: {}\n{}",
+                    day.number,
+                    seed,
+                    problems[0],
+                    generated.text
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use anyhow::{Error, Result};
+use common::{parse, Answer, Params};
+use std::{fs, time::Duration};
+
+use crate::{days::Day, stage};
+
+// Lives next to the example inputs in each day's directory
+pub const MANIFEST: &str = "expected_answers.txt";
+
+// One example input, the params it needs and the answers it should give
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub params: Params,
+    pub expected: [Option<Answer>; 2],
+}
+
+pub struct Outcome {
+    pub file: String,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Result<Answer>,
+}
+
+impl Outcome {
+    // The answer as text, or "error: " and the message for a part that is expected to fail
+    pub fn actual_text(&self) -> String {
+        match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {}", e),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.expected == *self.actual_text()
+    }
+}
+
+// A day without a manifest simply has no examples
+pub fn load(day: &Day) -> Result<Vec<Example>> {
+    let path = day.directory().join(MANIFEST);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = fs::read_to_string(&path)?;
+    parse_manifest(&text).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+}
+
+// Every example is a [file name] section of key = value lines. part1 and part2
+// hold the expected answers, any other key overrides the example's params file. An answer of
+// "error: " and a message expects the part to fail with that message
+fn parse_manifest(text: &str) -> Result<Vec<Example>> {
+    let mut sections: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if let Some(file) = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            sections.push((file.trim().to_string(), String::new()));
+        } else if let Some((_, body)) = sections.last_mut() {
+            body.push_str(line);
+            body.push('\n');
+        } else if !trimmed.is_empty() && !trimmed.starts_with('#') {
+            return Err(Error::msg(format!(
+                "{:?} is not inside an [example] section",
+                trimmed
+            )));
+        }
+    }
+
+    let mut examples = Vec::new();
+    for (file, body) in sections {
+        let mut params = body.parse::<Params>()?;
+        let mut answer = |key| params.remove(key).map(|text| text.parse()).transpose();
+        let expected = [answer("part1")?, answer("part2")?];
+        examples.push(Example {
+            file,
+            params,
+            expected,
+        });
+    }
+    Ok(examples)
+}
+
+// Runs every part with an expected answer against its example input
+pub fn check(day: &Day) -> Result<Vec<Outcome>> {
+    let mut outcomes = Vec::new();
+    for example in load(day)? {
+        let path = day.directory().join(&example.file);
+        let mut params = Params::for_input(&path)?;
+        params.extend(&example.params);
+        let mut elapsed = Duration::ZERO;
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+            .and_then(|input| {
+                stage::timed(&mut elapsed, || day.solution.parse_dyn(&input, &params))
+                    .map_err(|e| parse::in_file(e, path.display().to_string()))
+            });
+
+        for (part, expected) in (1..).zip(example.expected) {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let actual = match &parsed {
+                Ok(parsed) => stage::timed(&mut elapsed, || match part {
+                    1 => day.solution.part1_dyn(parsed.as_ref()),
+                    _ => day.solution.part2_dyn(parsed.as_ref()),
+                }),
+                Err(e) => Err(Error::msg(format!("parse failed: {}", e))),
+            };
+            outcomes.push(Outcome {
+                file: example.file.clone(),
+                part,
+                expected,
+                actual,
+            });
+        }
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn manifest_splits_answers_from_params() {
+        let text = "# comment\n[a.txt]\nsize = 7\npart1 = 22\n\n[b.txt]\npart2 = 6,1\n";
+        let mut params = Params::new();
+        params.set("size", "7");
+        assert_eq!(
+            parse_manifest(text).unwrap(),
+            vec![
+                Example {
+                    file: "a.txt".to_string(),
+                    params,
+                    expected: [Some(Answer::Integer(22)), None],
+                },
+                Example {
+                    file: "b.txt".to_string(),
+                    params: Params::new(),
+                    expected: [None, Some(Answer::Coordinate(6, 1))],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn manifest_rejects_lines_outside_a_section() {
+        assert!(parse_manifest("part1 = 3\n").is_err());
+    }
+
+    #[test]
+    fn every_example_gives_its_expected_answers() {
+        let mut failures = Vec::new();
+        for day in days::all() {
+            let outcomes = check(day).unwrap();
+            assert!(!outcomes.is_empty(), "day {} has no examples", day.number);
+            for outcome in outcomes.iter().filter(|o| !o.passed()) {
+                failures.push(format!(
+                    "day {} {} part {}: expected {}, got {:?}",
+                    day.number, outcome.file, outcome.part, outcome.expected, outcome.actual
+                ));
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
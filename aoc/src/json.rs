@@ -0,0 +1,70 @@
+use std::fmt::{self, Display, Write};
+
+// Just enough JSON writing for the runner's output, built up one field at a time
+#[derive(Default)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Object {
+        Object::default()
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> Object {
+        self.fields.push((key.to_string(), quote(value)));
+        self
+    }
+
+    // Written as is, so only for values that Display as a valid JSON number
+    pub fn number(mut self, key: &str, value: impl Display) -> Object {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('{')?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {}", quote(key), value)?;
+        }
+        f.write_char('}')
+    }
+}
+
+pub fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if c.is_control() => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn objects_escape_their_strings() {
+        let object = Object::new()
+            .number("day", 17)
+            .string("answer", "say \"hi\"\n\\");
+        assert_eq!(
+            object.to_string(),
+            r#"{"day": 17, "answer": "say \"hi\"\n\\"}"#
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use anyhow::{Error, Result};
+use common::Answer;
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+
+// What the puzzle site said about our answers, in each year's directory. Each input gets its
+// own table, since answers only mean something for the input they were given for:
+//
+// [day16.part2.3f2a9c0d1e4b5a67]
+// correct = "524"
+// too_high = ["530"]
+pub const FILE: &str = "answers.toml";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Feedback {
+    pub fn key(self) -> &'static str {
+        match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too_high",
+            Feedback::TooLow => "too_low",
+            Feedback::Wrong => "wrong",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Record {
+    pub correct: Option<Answer>,
+    pub too_high: Vec<Answer>,
+    pub too_low: Vec<Answer>,
+    pub wrong: Vec<Answer>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Matches,
+    // The accepted answer, which this one no longer is
+    Differs(Answer),
+    // Why the site would turn this answer down
+    KnownWrong(&'static str),
+    // Nothing accepted yet and nothing rules this answer out
+    Unverified,
+}
+
+impl Record {
+    pub fn check(&self, answer: &Answer) -> Check {
+        if let Some(correct) = &self.correct {
+            return if correct == answer {
+                Check::Matches
+            } else {
+                Check::Differs(correct.clone())
+            };
+        }
+        let rejected = [&self.too_high, &self.too_low, &self.wrong];
+        if rejected
+            .iter()
+            .any(|answers| answers.iter().any(|a| a == answer))
+        {
+            return Check::KnownWrong("already rejected");
+        }
+        // Hints only help with numbers, anything else just has to not be rejected already
+        let Some(value) = answer.as_integer() else {
+            return Check::Unverified;
+        };
+        let numbers = |answers: &[Answer]| {
+            answers
+                .iter()
+                .filter_map(Answer::as_integer)
+                .collect::<Vec<i128>>()
+        };
+        if numbers(&self.too_high).iter().any(|&high| value >= high) {
+            Check::KnownWrong("too high")
+        } else if numbers(&self.too_low).iter().any(|&low| value <= low) {
+            Check::KnownWrong("too low")
+        } else {
+            Check::Unverified
+        }
+    }
+
+    fn list(&mut self, feedback: Feedback) -> &mut Vec<Answer> {
+        match feedback {
+            Feedback::TooHigh => &mut self.too_high,
+            Feedback::TooLow => &mut self.too_low,
+            _ => &mut self.wrong,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    // Keyed by day, part and input hash
+    records: BTreeMap<(u8, u8, String), Record>,
+}
+
+impl Ledger {
+    // A missing file is an empty ledger
+    pub fn load(path: &Path) -> Result<Ledger> {
+        if !path.exists() {
+            return Ok(Ledger::default());
+        }
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&Record> {
+        self.records.get(&(day, part, input_hash.to_string()))
+    }
+
+    // Refuses anything that contradicts what was recorded before
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        answer: &Answer,
+        feedback: Feedback,
+    ) -> Result<()> {
+        let record = self
+            .records
+            .entry((day, part, input_hash.to_string()))
+            .or_default();
+        let rejected = [&record.too_high, &record.too_low, &record.wrong];
+        let was_rejected = rejected
+            .iter()
+            .any(|answers| answers.iter().any(|a| a == answer));
+        match (feedback, &record.correct) {
+            (Feedback::Correct, Some(correct)) if correct != answer => Err(Error::msg(format!(
+                "Day {} part {} already has {} accepted",
+                day, part, correct
+            ))),
+            (Feedback::Correct, _) if was_rejected => Err(Error::msg(format!(
+                "{} was already recorded as wrong",
+                answer
+            ))),
+            (Feedback::Correct, _) => {
+                record.correct = Some(answer.clone());
+                Ok(())
+            }
+            (_, Some(correct)) if correct == answer => Err(Error::msg(format!(
+                "{} was already recorded as correct",
+                answer
+            ))),
+            (_, _) => {
+                let list = record.list(feedback);
+                if !list.iter().any(|a| a == answer) {
+                    list.push(answer.clone());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// A stable 64-bit FNV-1a hash of the input text, so the ledger never holds the input itself
+pub fn input_hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+impl FromStr for Ledger {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Ledger> {
+        let mut ledger = Ledger::default();
+        let mut current: Option<(u8, u8, String)> = None;
+        for (i, line) in text.lines().enumerate() {
+            let error = |message: &str| Error::msg(format!("line {}: {}", i + 1, message));
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+                let key =
+                    parse_header(header).ok_or_else(|| error("expected [dayN.partP.hash]"))?;
+                ledger.records.entry(key.clone()).or_default();
+                current = Some(key);
+                continue;
+            }
+            let key = current
+                .as_ref()
+                .ok_or_else(|| error("expected a [dayN.partP.hash] table first"))?;
+            let record = ledger.records.get_mut(key).expect("Table was just added");
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected key = value"))?;
+            let value = value.trim();
+            match name.trim() {
+                "correct" => {
+                    record.correct =
+                        Some(parse_string_value(value).ok_or_else(|| error("expected a string"))?)
+                }
+                "too_high" | "too_low" | "wrong" => {
+                    let feedback = match name.trim() {
+                        "too_high" => Feedback::TooHigh,
+                        "too_low" => Feedback::TooLow,
+                        _ => Feedback::Wrong,
+                    };
+                    *record.list(feedback) =
+                        parse_list(value).ok_or_else(|| error("expected a list of strings"))?;
+                }
+                other => return Err(error(&format!("unknown key {:?}", other))),
+            }
+        }
+        Ok(ledger)
+    }
+}
+
+impl fmt::Display for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for ((day, part, hash), record) in &self.records {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            writeln!(f, "[day{}.part{}.{}]", day, part, hash)?;
+            if let Some(correct) = &record.correct {
+                writeln!(
+                    f,
+                    "{} = {}",
+                    Feedback::Correct.key(),
+                    quote(&correct.to_string())
+                )?;
+            }
+            for (feedback, answers) in [
+                (Feedback::TooHigh, &record.too_high),
+                (Feedback::TooLow, &record.too_low),
+                (Feedback::Wrong, &record.wrong),
+            ] {
+                if !answers.is_empty() {
+                    let quoted = answers
+                        .iter()
+                        .map(|a| quote(&a.to_string()))
+                        .collect::<Vec<String>>();
+                    writeln!(f, "{} = [{}]", feedback.key(), quoted.join(", "))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_header(header: &str) -> Option<(u8, u8, String)> {
+    let mut parts = header.trim().split('.');
+    let day = parts.next()?.strip_prefix("day")?.parse().ok()?;
+    let part = parts.next()?.strip_prefix("part")?.parse().ok()?;
+    let hash = parts.next()?.to_string();
+    if parts.next().is_some() || hash.is_empty() {
+        return None;
+    }
+    Some((day, part, hash))
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Answers are always written quoted, and read back as whatever kind their text is
+//
+// Reads one quoted string off the front of text, returning it and what follows
+fn parse_string(text: &str) -> Option<(String, &str)> {
+    let mut chars = text.strip_prefix('"')?.char_indices();
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &text[i + 2..])),
+            '\\' => value.push(chars.next()?.1),
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+fn parse_string_value(text: &str) -> Option<Answer> {
+    let (value, rest) = parse_string(text)?;
+    let Ok(answer) = value.parse();
+    rest.trim().is_empty().then_some(answer)
+}
+
+fn parse_list(text: &str) -> Option<Vec<Answer>> {
+    let mut rest = text.strip_prefix('[')?.trim_start();
+    let mut values = Vec::new();
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            return after.trim().is_empty().then_some(values);
+        }
+        let (value, after) = parse_string(rest)?;
+        let Ok(answer) = value.parse();
+        values.push(answer);
+        rest = after.trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(text: &str) -> Answer {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn ledger_round_trips_through_its_file() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record(16, 2, "abc", &answer("530"), Feedback::TooHigh)
+            .unwrap();
+        ledger
+            .record(16, 2, "abc", &answer("524"), Feedback::Correct)
+            .unwrap();
+        ledger
+            .record(17, 1, "def", &answer("1,\"2\""), Feedback::Wrong)
+            .unwrap();
+        let text = ledger.to_string();
+        assert_eq!(text.parse::<Ledger>().unwrap(), ledger);
+        assert!(text.contains("[day16.part2.abc]\ncorrect = \"524\"\ntoo_high = [\"530\"]\n"));
+    }
+
+    #[test]
+    fn hints_rule_out_answers_past_them() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record(1, 1, "h", &answer("100"), Feedback::TooHigh)
+            .unwrap();
+        ledger
+            .record(1, 1, "h", &answer("10"), Feedback::TooLow)
+            .unwrap();
+        let record = ledger.get(1, 1, "h").unwrap();
+        assert_eq!(record.check(&answer("150")), Check::KnownWrong("too high"));
+        assert_eq!(
+            record.check(&answer("10")),
+            Check::KnownWrong("already rejected")
+        );
+        assert_eq!(record.check(&answer("5")), Check::KnownWrong("too low"));
+        assert_eq!(record.check(&answer("50")), Check::Unverified);
+
+        ledger
+            .record(1, 1, "h", &answer("42"), Feedback::Correct)
+            .unwrap();
+        let record = ledger.get(1, 1, "h").unwrap();
+        assert_eq!(record.check(&answer("42")), Check::Matches);
+        assert_eq!(record.check(&answer("50")), Check::Differs(answer("42")));
+    }
+
+    #[test]
+    fn contradictions_are_refused() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record(1, 1, "h", &answer("7"), Feedback::Wrong)
+            .unwrap();
+        assert!(ledger
+            .record(1, 1, "h", &answer("7"), Feedback::Correct)
+            .is_err());
+        ledger
+            .record(1, 1, "h", &answer("8"), Feedback::Correct)
+            .unwrap();
+        assert!(ledger
+            .record(1, 1, "h", &answer("9"), Feedback::Correct)
+            .is_err());
+        assert!(ledger
+            .record(1, 1, "h", &answer("8"), Feedback::TooLow)
+            .is_err());
+    }
+
+    #[test]
+    fn answers_match_whatever_kind_they_were_recorded_as() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record(18, 2, "h", &answer("6,1"), Feedback::Correct)
+            .unwrap();
+        ledger
+            .record(14, 2, "h", &answer("7000"), Feedback::TooHigh)
+            .unwrap();
+        let text = ledger.to_string();
+        let ledger = text.parse::<Ledger>().unwrap();
+        assert_eq!(
+            ledger
+                .get(18, 2, "h")
+                .unwrap()
+                .check(&Answer::Coordinate(6, 1)),
+            Check::Matches
+        );
+        let tree = Answer::grid(7502, "X.\n.X");
+        assert_eq!(
+            ledger.get(14, 2, "h").unwrap().check(&tree),
+            Check::KnownWrong("too high")
+        );
+    }
+}
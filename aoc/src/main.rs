@@ -0,0 +1,721 @@
+mod bench;
+mod cli;
+mod days;
+mod examples;
+mod json;
+mod ledger;
+mod scaffold;
+mod stage;
+mod table;
+
+use anyhow::{Error, Result};
+use common::{
+    allocator::{self, Usage},
+    animation, image, input, parallel, params_path, parse,
+    random::Rng,
+    repl, Answer, Params,
+};
+use std::{any::Any, env, fs, io, path::PathBuf, process::ExitCode, time::Duration};
+
+use bench::Baseline;
+use cli::{
+    AnimateOptions, AnswerOptions, BenchOptions, Command, Format, GenerateOptions, ImageOptions,
+    LintOptions, RunOptions,
+};
+use days::{Day, Year};
+use json::Object;
+use ledger::{Check, Ledger};
+use table::Table;
+
+// Only counts while a stage runs under --profile-alloc
+#[global_allocator]
+static ALLOCATOR: allocator::Counting = allocator::Counting;
+
+fn main() -> ExitCode {
+    let result = cli::parse(env::args().skip(1)).and_then(|(year, command)| {
+        // Only new may name a year that has no days yet
+        let find = || days::find_year(year);
+        match command {
+            Command::Run(options) => run(find()?, options),
+            Command::Bench(options) => bench(find()?, options),
+            Command::Animate(options) => animate(find()?, options),
+            Command::Repl(options) => start_repl(find()?, options),
+            Command::Image(options) => write_image(find()?, options),
+            Command::Generate(options) => generate(find()?, options),
+            Command::New(day) => new_day(year, day),
+            Command::Answer(options) => record_answer(find()?, options),
+            Command::Verify(day) => verify(find()?, day),
+            Command::List => list(find()?),
+            Command::Examples(day) => check_examples(find()?, day),
+            Command::Lint(options) => lint(find()?, options),
+        }
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list(year: &Year) -> Result<()> {
+    let mut table = Table::new(&["Day", "Input"]);
+    for day in year.days {
+        let input = day.default_input();
+        let status = if input.exists() { "found" } else { "missing" };
+        table.push(vec![
+            day.number.to_string(),
+            format!("{} ({})", input.display(), status),
+        ]);
+    }
+    table.print();
+    Ok(())
+}
+
+fn check_examples(year: &'static Year, day: Option<u8>) -> Result<()> {
+    let selected = select_days(year, day)?;
+    let mut table = Table::new(&["Day", "Example", "Part", "Expected", "Actual", "Result"]);
+    let mut passed = 0;
+    let mut failed = 0;
+    for day in selected {
+        for outcome in examples::check(day)? {
+            let actual = outcome.actual_text();
+            let result = if outcome.passed() {
+                passed += 1;
+                "pass"
+            } else {
+                failed += 1;
+                "FAIL"
+            };
+            table.push(vec![
+                day.number.to_string(),
+                outcome.file,
+                outcome.part.to_string(),
+                outcome.expected.to_string(),
+                actual,
+                result.to_string(),
+            ]);
+        }
+    }
+    table.print();
+    println!("\n{} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        return Err(Error::msg(format!("{} example answer(s) wrong", failed)));
+    }
+    Ok(())
+}
+
+// Reports every problem in each input, then anything parsing still trips over
+fn lint(year: &'static Year, options: LintOptions) -> Result<()> {
+    let mut total = 0;
+    for day in select_days(year, options.day)? {
+        let path = options.input.clone().unwrap_or_else(|| day.default_input());
+        if options.input.is_none() && !path.exists() {
+            println!("Day {}: no input", day.number);
+            continue;
+        }
+        let text = input::read(&path)?;
+        let params = Params::for_input(&path)?;
+        let file = path.display().to_string();
+
+        let mut problems = (day.lint)(&text, &params);
+        problems.sort_by_key(|problem| (problem.line, problem.column));
+        let mut problems = problems
+            .into_iter()
+            .map(|problem| problem.in_file(file.as_str()).to_string())
+            .collect::<Vec<String>>();
+        if problems.is_empty() {
+            if let Err(e) = day.solution.parse_dyn(&text, &params) {
+                problems.push(parse::in_file(e, file.as_str()).to_string());
+            }
+        }
+        for problem in &problems {
+            println!("{}", problem);
+        }
+        match problems.len() {
+            0 => println!("Day {}: ok", day.number),
+            count => println!("Day {}: {} problem(s)", day.number, count),
+        }
+        total += problems.len();
+    }
+    if total > 0 {
+        return Err(Error::msg(format!("{} problem(s) found", total)));
+    }
+    Ok(())
+}
+
+fn select_days(year: &'static Year, day: Option<u8>) -> Result<Vec<&'static Day>> {
+    match day {
+        Some(number) => Ok(vec![find_day(year, number)?]),
+        None => Ok(year.days.iter().collect()),
+    }
+}
+
+fn find_day(year: &'static Year, number: u8) -> Result<&'static Day> {
+    year.find(number)
+        .ok_or_else(|| Error::msg(format!("No solver for day {} of {}", number, year.number)))
+}
+
+fn run(year: &'static Year, options: RunOptions) -> Result<()> {
+    let selected = select_days(year, options.day)?;
+    if let Some(jobs) = options.jobs {
+        parallel::set_jobs(jobs);
+    }
+    // Days run side by side, the table still lists them in order. Allocations are counted
+    // for the whole process, so profiling runs them one at a time
+    let runs = if options.profile_alloc {
+        selected.iter().map(|day| run_day(day, &options)).collect()
+    } else {
+        parallel::map(&selected, |day| run_day(day, &options))
+    };
+    if options.format == Format::Json {
+        return run_json(selected.into_iter().zip(runs));
+    }
+
+    let mut table = Table::new(&["Day", "Part 1", "Part 2", "Parse", "Solve"]);
+    let mut usage_table = Table::new(&["Day", "Stage", "Allocations", "Allocated", "Peak live"]);
+    let mut failures = 0;
+    let mut total_parse = Duration::ZERO;
+    let mut total_solve = Duration::ZERO;
+    for (day, day_run) in selected.into_iter().zip(runs) {
+        match day_run {
+            Ok(day_run) => {
+                for (stage, usage) in ["parse", "part 1", "part 2"].iter().zip(day_run.usage) {
+                    if let Some(usage) = usage {
+                        usage_table.push(vec![
+                            day.number.to_string(),
+                            stage.to_string(),
+                            usage.allocations.to_string(),
+                            allocator::format_bytes(usage.bytes),
+                            allocator::format_bytes(usage.peak_live),
+                        ]);
+                    }
+                }
+                let solve_time = day_run.solve_time();
+                total_parse += day_run.parse_time;
+                total_solve += solve_time;
+                table.push(vec![
+                    day.number.to_string(),
+                    day_run.part1.map(|a| a.to_string()).unwrap_or_default(),
+                    day_run.part2.map(|a| a.to_string()).unwrap_or_default(),
+                    format_duration(day_run.parse_time),
+                    format_duration(solve_time),
+                ]);
+            }
+            Err(e) => {
+                failures += 1;
+                table.push(vec![
+                    day.number.to_string(),
+                    format!("error: {}", e),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]);
+            }
+        }
+    }
+    if options.all {
+        table.push(vec![
+            "Total".to_string(),
+            String::new(),
+            String::new(),
+            format_duration(total_parse),
+            format_duration(total_solve),
+        ]);
+    }
+    table.print();
+    if options.profile_alloc {
+        println!();
+        usage_table.print();
+    }
+
+    if failures > 0 {
+        return Err(Error::msg(format!("{} day(s) failed", failures)));
+    }
+    Ok(())
+}
+
+// One object per day and part, or a single one with the error when a day fails
+fn run_json<'a>(runs: impl Iterator<Item = (&'a Day, Result<DayRun>)>) -> Result<()> {
+    let mut objects = Vec::new();
+    let mut failures = 0;
+    for (day, day_run) in runs {
+        match day_run {
+            Ok(day_run) => {
+                let answers = [day_run.part1, day_run.part2];
+                for (part, (answer, elapsed)) in answers.iter().zip(day_run.part_times).enumerate()
+                {
+                    let Some(answer) = answer else {
+                        continue;
+                    };
+                    let mut object = Object::new()
+                        .number("year", day.year)
+                        .number("day", day.number)
+                        .number("part", part + 1)
+                        .string("answer", &answer.to_string())
+                        .string("type", answer.kind())
+                        .number("parse_ms", format!("{:.3}", millis(day_run.parse_time)))
+                        .number("elapsed_ms", format!("{:.3}", millis(elapsed)));
+                    if let Some(rows) = answer.rows() {
+                        object = object.string("grid", &rows.join("\n"));
+                    }
+                    if let (Some(parse), Some(usage)) = (day_run.usage[0], day_run.usage[part + 1])
+                    {
+                        object = usage_fields(object, "parse_", parse);
+                        object = usage_fields(object, "", usage);
+                    }
+                    objects.push(object);
+                }
+            }
+            Err(e) => {
+                failures += 1;
+                objects.push(
+                    Object::new()
+                        .number("year", day.year)
+                        .number("day", day.number)
+                        .string("error", &e.to_string()),
+                );
+            }
+        }
+    }
+
+    println!("[");
+    for (i, object) in objects.iter().enumerate() {
+        let separator = if i + 1 < objects.len() { "," } else { "" };
+        println!("  {}{}", object, separator);
+    }
+    println!("]");
+
+    if failures > 0 {
+        return Err(Error::msg(format!("{} day(s) failed", failures)));
+    }
+    Ok(())
+}
+
+fn usage_fields(object: Object, prefix: &str, usage: Usage) -> Object {
+    object
+        .number(&format!("{}allocations", prefix), usage.allocations)
+        .number(&format!("{}allocated_bytes", prefix), usage.bytes)
+        .number(&format!("{}peak_bytes", prefix), usage.peak_live)
+}
+
+struct DayRun {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    parse_time: Duration,
+    part_times: [Duration; 2],
+    // Parse, part 1 and part 2, for the stages that ran under --profile-alloc
+    usage: [Option<Usage>; 3],
+}
+
+impl DayRun {
+    fn solve_time(&self) -> Duration {
+        self.part_times.iter().sum()
+    }
+}
+
+// The input text a day runs on, with its params file and any --set on top
+pub struct DayInput {
+    path: PathBuf,
+    text: String,
+    params: Params,
+}
+
+fn load_input(day: &Day, options: &RunOptions) -> Result<DayInput> {
+    if let Some(size) = options.generate {
+        let generated = (day.generate)(&mut Rng::new(options.seed), size);
+        let mut params = generated.params;
+        params.extend(&options.set);
+        return Ok(DayInput {
+            path: PathBuf::from(format!(
+                "day {} generated with size {} and seed {}",
+                day.number, size, options.seed
+            )),
+            text: generated.text,
+            params,
+        });
+    }
+    let path = options.input.clone().unwrap_or_else(|| day.default_input());
+    let text = input::read(&path)?;
+    let mut params = Params::for_input(&path)?;
+    params.extend(&options.set);
+    Ok(DayInput { path, text, params })
+}
+
+fn run_day(day: &Day, options: &RunOptions) -> Result<DayRun> {
+    let input = load_input(day, options)?;
+    solve(day, &input, options.part, options.profile_alloc)
+}
+
+// Parses once, then runs only the parts that were asked for
+fn solve(day: &Day, input: &DayInput, part: Option<u8>, profile_alloc: bool) -> Result<DayRun> {
+    let wants = |wanted| part.is_none_or(|p| p == wanted);
+
+    let mut parse_time = Duration::ZERO;
+    let mut part_times = [Duration::ZERO; 2];
+    let mut usage = [None; 3];
+    let parsed = stage::timed(&mut parse_time, || {
+        counted(profile_alloc, &mut usage[0], || {
+            day.solution.parse_dyn(&input.text, &input.params)
+        })
+    })
+    .map_err(|e| parse::in_file(e, input.path.display().to_string()))?;
+    let part1 = if wants(1) {
+        Some(stage::timed(&mut part_times[0], || {
+            counted(profile_alloc, &mut usage[1], || {
+                day.solution.part1_dyn(parsed.as_ref())
+            })
+        })?)
+    } else {
+        None
+    };
+    let part2 = if wants(2) {
+        Some(stage::timed(&mut part_times[1], || {
+            counted(profile_alloc, &mut usage[2], || {
+                day.solution.part2_dyn(parsed.as_ref())
+            })
+        })?)
+    } else {
+        None
+    };
+
+    Ok(DayRun {
+        part1,
+        part2,
+        parse_time,
+        part_times,
+        usage,
+    })
+}
+
+// Runs a stage, keeping what it allocated in usage when profiling
+fn counted<T>(profile_alloc: bool, usage: &mut Option<Usage>, stage: impl FnOnce() -> T) -> T {
+    if !profile_alloc {
+        return stage();
+    }
+    let (result, counted) = allocator::measure(stage);
+    *usage = Some(counted);
+    result
+}
+
+// Days are benched one at a time so they don't slow each other down, --jobs only sets how
+// many threads a solver may use
+fn bench(year: &'static Year, options: BenchOptions) -> Result<()> {
+    let selected = select_days(year, options.run.day)?;
+    if let Some(jobs) = options.run.jobs {
+        parallel::set_jobs(jobs);
+    }
+    let wants = |part| options.run.part.is_none_or(|p| p == part);
+    let baseline_path = options.baseline.clone().unwrap_or_else(|| {
+        let file = bench::default_file(options.run.generate, options.run.seed);
+        year.directory().join(file)
+    });
+    let mut baseline = Baseline::load(&baseline_path)?;
+
+    let mut table = Table::new(&["Day", "Stage", "Min", "Median", "Max", "Baseline", "Change"]);
+    let mut failures = 0;
+    let mut regressions = 0;
+    for day in selected {
+        let stats = load_input(day, &options.run).and_then(|input| {
+            let stats = bench::bench_day(
+                day,
+                &input,
+                [wants(1), wants(2)],
+                options.warmup,
+                options.runs,
+            )?;
+            Ok((stats, bench::input_key(&input)))
+        });
+        let (stats, input_key) = match stats {
+            Ok(stats) => stats,
+            Err(e) => {
+                failures += 1;
+                let mut row = vec![day.number.to_string(), format!("error: {}", e)];
+                row.resize(7, String::new());
+                table.push(row);
+                continue;
+            }
+        };
+
+        for (stage, stat) in bench::STAGES.iter().zip(stats) {
+            let Some(stat) = stat else {
+                continue;
+            };
+            let previous = baseline.get(day.number, stage, &input_key);
+            let change = match previous.map(|previous| bench::change(stat.median, previous)) {
+                Some(Some(change)) => {
+                    let flag = if change > options.threshold {
+                        regressions += 1;
+                        "  SLOWER"
+                    } else {
+                        ""
+                    };
+                    format!("{:+.1}%{}", change, flag)
+                }
+                Some(None) => "n/a".to_string(),
+                None => String::new(),
+            };
+            let previous = previous.map(format_duration).unwrap_or_default();
+            table.push(vec![
+                day.number.to_string(),
+                stage.to_string(),
+                format_duration(stat.min),
+                format_duration(stat.median),
+                format_duration(stat.max),
+                previous,
+                change,
+            ]);
+            if options.save {
+                baseline.record(day.number, stage, &input_key, stat.median);
+            }
+        }
+    }
+    table.print();
+
+    if options.save {
+        baseline.save(&baseline_path)?;
+        println!("\nSaved baseline to {}", baseline_path.display());
+    }
+    if failures > 0 {
+        return Err(Error::msg(format!("{} day(s) failed", failures)));
+    }
+    if regressions > 0 && !options.save {
+        return Err(Error::msg(format!(
+            "{} stage(s) more than {}% slower than the baseline",
+            regressions, options.threshold
+        )));
+    }
+    Ok(())
+}
+
+fn record_answer(year: &'static Year, options: AnswerOptions) -> Result<()> {
+    let day = find_day(year, options.day)?;
+    let path = options.input.unwrap_or_else(|| day.default_input());
+    let input_hash = ledger::input_hash(&input::read(&path)?);
+
+    let ledger_path = year.directory().join(ledger::FILE);
+    let mut ledger = Ledger::load(&ledger_path)?;
+    ledger.record(
+        day.number,
+        options.part,
+        &input_hash,
+        &options.answer,
+        options.feedback,
+    )?;
+    ledger.save(&ledger_path)?;
+    println!(
+        "Recorded {} as {} for day {} part {} ({})",
+        options.answer,
+        options.feedback.key().replace('_', " "),
+        day.number,
+        options.part,
+        path.display()
+    );
+    Ok(())
+}
+
+// Solves every day's real input again and checks the answers against the ledger
+fn verify(year: &'static Year, day: Option<u8>) -> Result<()> {
+    let selected = select_days(year, day)?;
+    let ledger = Ledger::load(&year.directory().join(ledger::FILE))?;
+
+    let mut table = Table::new(&["Day", "Part", "Answer", "Accepted", "Result"]);
+    let mut failures = 0;
+    let mut row = |day: &Day, part: &str, answer: &str, accepted: &str, result: &str| {
+        table.push(vec![
+            day.number.to_string(),
+            part.to_string(),
+            answer.to_string(),
+            accepted.to_string(),
+            result.to_string(),
+        ]);
+    };
+    for day in selected {
+        if !day.default_input().exists() {
+            row(day, "", "", "", "no input");
+            continue;
+        }
+        let solved = load_input(day, &RunOptions::default()).and_then(|input| {
+            let day_run = solve(day, &input, None, false)?;
+            Ok((ledger::input_hash(&input.text), day_run))
+        });
+        let (input_hash, day_run) = match solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                failures += 1;
+                row(day, "", "", "", &format!("error: {}", e));
+                continue;
+            }
+        };
+
+        for (part, answer) in [(1, day_run.part1), (2, day_run.part2)] {
+            // verify always solves both parts
+            let Some(answer) = answer else {
+                continue;
+            };
+            let (accepted, result) = match ledger.get(day.number, part, &input_hash) {
+                None => (String::new(), "not recorded".to_string()),
+                Some(record) => {
+                    let accepted = record
+                        .correct
+                        .as_ref()
+                        .map(|a| a.to_string())
+                        .unwrap_or_default();
+                    let result = match record.check(&answer) {
+                        Check::Matches => "ok".to_string(),
+                        Check::Differs(_) => "CHANGED".to_string(),
+                        Check::KnownWrong(reason) => format!("WRONG ({})", reason),
+                        Check::Unverified => "unverified".to_string(),
+                    };
+                    (accepted, result)
+                }
+            };
+            if result == "CHANGED" || result.starts_with("WRONG") {
+                failures += 1;
+            }
+            row(
+                day,
+                &part.to_string(),
+                &answer.to_string(),
+                &accepted,
+                &result,
+            );
+        }
+    }
+    table.print();
+
+    if failures > 0 {
+        return Err(Error::msg(format!(
+            "{} answer(s) do not match the ledger",
+            failures
+        )));
+    }
+    Ok(())
+}
+
+fn new_day(year: Option<u16>, number: u8) -> Result<()> {
+    let year = match year {
+        Some(year) => year,
+        None => days::find_year(None)?.number,
+    };
+    scaffold::new_day(year, number)?;
+    let directory = format!("{}/day{}", year, number);
+    println!(
+        "Created {}, registered it with the workspace and the runner.",
+        directory
+    );
+    println!(
+        "Put the example in {}/puzzle_input_example.txt and its answers in",
+        directory
+    );
+    println!(
+        "{}/{}, then rebuild to run it.",
+        directory,
+        examples::MANIFEST
+    );
+    Ok(())
+}
+
+fn animate(year: &'static Year, options: AnimateOptions) -> Result<()> {
+    // The parser only lets a single --day through
+    let day = select_days(year, options.run.day)?[0];
+    let animation = day
+        .animation
+        .ok_or_else(|| Error::msg(format!("Day {} has no animation", day.number)))?;
+    let parsed = load_parsed(day, &options.run)?;
+    let frames = animation.frames_dyn(parsed.as_ref())?;
+    animation::play(frames, options.fps)?;
+    Ok(())
+}
+
+// Days without their own commands still get part1, part2 and help
+fn start_repl(year: &'static Year, options: RunOptions) -> Result<()> {
+    let day = select_days(year, options.day)?[0];
+    let parsed = load_parsed(day, &options)?;
+    let session = day
+        .repl
+        .map(|repl| repl.session(parsed.as_ref()))
+        .transpose()?;
+    let prompt = format!("day{}> ", day.number);
+    repl::run(
+        day.solution,
+        parsed.as_ref(),
+        session,
+        &prompt,
+        io::stdin().lock(),
+        io::stdout(),
+    )
+}
+
+// Writes the day's picture, or its animation frames as a numbered sequence
+fn write_image(year: &'static Year, options: ImageOptions) -> Result<()> {
+    let day = select_days(year, options.run.day)?[0];
+    let parsed = load_parsed(day, &options.run)?;
+    if options.frames {
+        let animation = day
+            .animation
+            .ok_or_else(|| Error::msg(format!("Day {} has no animation", day.number)))?;
+        let frames = animation.frames_dyn(parsed.as_ref())?()
+            .enumerate()
+            .skip(options.skip)
+            .take(options.take.unwrap_or(usize::MAX));
+        let count = image::write_frames(&options.output, frames, &options.palette, options.scale)?;
+        println!(
+            "Wrote {} frames, {} to {}",
+            count,
+            image::numbered(&options.output, options.skip).display(),
+            image::numbered(&options.output, options.skip + count.saturating_sub(1)).display()
+        );
+    } else {
+        let picture = day.picture.ok_or_else(|| {
+            Error::msg(format!("Day {} has no picture, try --frames", day.number))
+        })?;
+        let text = picture.picture_dyn(parsed.as_ref())?;
+        image::write(&options.output, &text, &options.palette, options.scale)?;
+        println!("Wrote {}", options.output.display());
+    }
+    Ok(())
+}
+
+fn load_parsed(day: &Day, options: &RunOptions) -> Result<Box<dyn Any>> {
+    let input = load_input(day, options)?;
+    day.solution
+        .parse_dyn(&input.text, &input.params)
+        .map_err(|e| parse::in_file(e, input.path.display().to_string()))
+}
+
+// Prints a random input, or writes it and its params for replaying with --input
+fn generate(year: &'static Year, options: GenerateOptions) -> Result<()> {
+    let day = find_day(year, options.day)?;
+    let generated = (day.generate)(&mut Rng::new(options.seed), options.size);
+    let Some(output) = options.output else {
+        print!("{}", generated.text);
+        for (key, value) in generated.params.iter() {
+            eprintln!("param {} = {}", key, value);
+        }
+        return Ok(());
+    };
+
+    fs::write(&output, &generated.text)
+        .map_err(|e| Error::msg(format!("{}: {}", output.display(), e)))?;
+    if !generated.params.is_empty() {
+        let path = params_path(&output);
+        let text = generated
+            .params
+            .iter()
+            .map(|(key, value)| format!("{} = {}\n", key, value))
+            .collect::<String>();
+        fs::write(&path, text).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))?;
+    }
+    Ok(())
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    format!("{:.3}ms", millis(elapsed))
+}
+
+fn millis(elapsed: Duration) -> f64 {
+    elapsed.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,301 @@
+use anyhow::{Error, Result};
+use std::{fs, path::Path};
+
+use crate::{days, examples};
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc{Y}-day{N}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+anyhow = "1.0.95"
+common = { path = "../../common" }
+grid = { path = "../../grid" }
+"#;
+
+const LIB_RS: &str = r#"mod generate;
+
+use anyhow::Result;
+use common::{parse, Answer, Lint, Params, ParseError, Solution};
+
+pub struct Day{N};
+
+impl Solution for Day{N} {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse::lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }
+
+    // Placeholder until the puzzle is solved
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(lines.len().into())
+    }
+
+    // Placeholder until the puzzle is solved
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        Ok(lines.len().into())
+    }
+}
+
+// Placeholder until the input's grammar is known
+impl Lint for Day{N} {
+    fn lint(_input: &str, _params: &Params) -> Vec<ParseError> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_parses() {
+        Day{N}::parse(include_str!("../puzzle_input_example.txt")).unwrap();
+    }
+}
+"#;
+
+const GENERATE_RS: &str = r#"use common::{random::Rng, Generate, Generated};
+
+use crate::Day{N};
+
+// size is the number of lines
+impl Generate for Day{N} {
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            text += &format!("{}\n", rng.range(0..=99));
+        }
+        Generated::new(text)
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"use anyhow::Result;
+use common::{input, Solution};
+use aoc{Y}_day{N}::Day{N};
+
+fn main() -> Result<()> {
+    let input = input::parse_from_args::<Day{N}>()?;
+
+    println!("Part 1: {}", Day{N}::part1(&input)?);
+    println!("Part 2: {}", Day{N}::part2(&input)?);
+
+    Ok(())
+}
+"#;
+
+// What the stub gives for the empty example, so the examples pass from the start
+const MANIFEST: &str = "[puzzle_input_example.txt]
+# Placeholders, replace with the example's answers from the puzzle text
+part1 = 0
+part2 = 0
+";
+
+// Creates YEAR/dayN with a stub solver and registers it with the workspace and the runner
+pub fn new_day(year: u16, number: u8) -> Result<()> {
+    if !(1..=25).contains(&number) {
+        return Err(Error::msg("Advent of Code days run from 1 to 25"));
+    }
+    let workspace = days::workspace_dir();
+    let directory = workspace.join(format!("{}/day{}", year, number));
+    if directory.exists() {
+        return Err(Error::msg(format!(
+            "{} already exists",
+            directory.display()
+        )));
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("{Y}", &year.to_string())
+            .replace("{N}", &number.to_string())
+    };
+    fs::create_dir_all(directory.join("src"))?;
+    fs::write(directory.join("Cargo.toml"), fill(CARGO_TOML))?;
+    fs::write(directory.join("src/lib.rs"), fill(LIB_RS))?;
+    fs::write(directory.join("src/generate.rs"), fill(GENERATE_RS))?;
+    fs::write(directory.join("src/main.rs"), fill(MAIN_RS))?;
+    fs::write(directory.join("puzzle_input_example.txt"), "")?;
+    fs::write(directory.join(examples::MANIFEST), MANIFEST)?;
+
+    edit(&workspace.join("Cargo.toml"), |text| {
+        add_workspace_member(text, year, number)
+    })?;
+    edit(&workspace.join("aoc/Cargo.toml"), |text| {
+        add_dependency(text, year, number)
+    })?;
+    edit(&workspace.join("aoc/src/days.rs"), |text| {
+        register_day(text, year, number)
+    })?;
+    Ok(())
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let text = fs::read_to_string(path)?;
+    let changed = change(&text).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))?;
+    fs::write(path, changed)?;
+    Ok(())
+}
+
+fn add_workspace_member(text: &str, year: u16, number: u8) -> Result<String> {
+    let line = format!("    \"{}/day{}\",", year, number);
+    insert_day_line(text, "members = [", "]", &line)
+}
+
+fn add_dependency(text: &str, year: u16, number: u8) -> Result<String> {
+    let line = format!(
+        "aoc{0}-day{1} = {{ path = \"../{0}/day{1}\" }}",
+        year, number
+    );
+    insert_day_line(text, "[dependencies]", "[", &line)
+}
+
+// Adds the day to its year's table, starting the table and its YEARS entry for a new year
+fn register_day(text: &str, year: u16, number: u8) -> Result<String> {
+    let prefix = format!("const DAYS_{}: [Day; ", year);
+    let line = format!(
+        "    Day::new({0}, {1}, &aoc{0}_day{1}::Day{1}),",
+        year, number
+    );
+    if text.contains(&prefix) {
+        let text = bump_count(text, &prefix)?;
+        return insert_day_line(&text, &prefix, "];", &line);
+    }
+
+    let text = bump_count(text, "pub const YEARS: [Year; ")?;
+    let years_start = text
+        .find("pub const YEARS")
+        .ok_or_else(|| Error::msg("Cannot find the YEARS table"))?;
+    let years_end = years_start
+        + text[years_start..]
+            .find("];")
+            .ok_or_else(|| Error::msg("Cannot find the end of the YEARS table"))?;
+    let entries = text[..years_end].trim_end();
+    let entry = format!("Year::new({0}, &DAYS_{0})", year);
+    let separator = if entries.ends_with(',') {
+        "\n    "
+    } else {
+        ", "
+    };
+    let text = format!("{}{}{}{}", entries, separator, entry, &text[years_end..]);
+
+    // After the last year's table, so the tables stay in order
+    let last_table = text
+        .rfind("const DAYS_")
+        .ok_or_else(|| Error::msg("Cannot find a DAYS table"))?;
+    let table_end = last_table
+        + text[last_table..]
+            .find("\n];\n")
+            .ok_or_else(|| Error::msg("Cannot find the end of the DAYS table"))?
+        + "\n];\n".len();
+    Ok(format!(
+        "{}\n{}1] = [\n{}\n];\n{}",
+        &text[..table_end],
+        prefix,
+        line,
+        &text[table_end..]
+    ))
+}
+
+// Adds one to the length in the array type that follows prefix
+fn bump_count(text: &str, prefix: &str) -> Result<String> {
+    let count_start = text
+        .find(prefix)
+        .ok_or_else(|| Error::msg(format!("Cannot find {}", prefix.trim_end())))?
+        + prefix.len();
+    let count_end = count_start
+        + text[count_start..]
+            .find(']')
+            .ok_or_else(|| Error::msg("Cannot read the length of the table"))?;
+    let count = text[count_start..count_end].parse::<usize>()?;
+    Ok(format!(
+        "{}{}{}",
+        &text[..count_start],
+        count + 1,
+        &text[count_end..]
+    ))
+}
+
+// Adds the line to the block that opens on the line containing start and closes on the
+// first line starting with end (or at the end of the file), ahead of any later day so the
+// years and days stay in order
+fn insert_day_line(text: &str, start: &str, end: &str, line: &str) -> Result<String> {
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    let first = lines
+        .iter()
+        .position(|l| l.contains(start))
+        .ok_or_else(|| Error::msg(format!("Cannot find {}", start)))?;
+    let last = lines[first + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with(end))
+        .map_or(lines.len(), |i| first + 1 + i);
+    let key = day_key(line);
+    let at = (first + 1..last)
+        .find(|&i| day_key(lines[i]) > key)
+        .unwrap_or(last);
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// The year and N of the first "YEAR/dayN", "aocYEAR-dayN" or "aocYEAR_dayN" in the line
+fn day_key(line: &str) -> Option<(u16, u8)> {
+    let at = line.find("day")?;
+    let digits = line[at + 3..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    let before = line[..at].strip_suffix(['/', '-', '_'])?;
+    let year = before.get(before.len().checked_sub(4)?..)?;
+    Some((year.parse().ok()?, digits.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members_stay_in_year_and_day_order() {
+        let text =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2024/day1\",\n    \"2024/day3\",\n]\n";
+        assert_eq!(
+            add_workspace_member(text, 2024, 2).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2024/day1\",\n    \"2024/day2\",\n    \"2024/day3\",\n]\n"
+        );
+        assert_eq!(
+            add_workspace_member(text, 2023, 20).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2023/day20\",\n    \"2024/day1\",\n    \"2024/day3\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn dependency_goes_at_the_end_of_the_last_table() {
+        let text = "[dependencies]\ncommon = { path = \"../common\" }\naoc2024-day19 = { path = \"../2024/day19\" }\n";
+        assert_eq!(
+            add_dependency(text, 2024, 20).unwrap(),
+            format!("{}aoc2024-day20 = {{ path = \"../2024/day20\" }}\n", text)
+        );
+    }
+
+    #[test]
+    fn days_table_grows_by_one() {
+        let text = "const DAYS_2024: [Day; 2] = [\n    Day::new(2024, 1, &aoc2024_day1::Day1),\n    Day::new(2024, 19, &aoc2024_day19::Day19),\n];\n";
+        assert_eq!(
+            register_day(text, 2024, 20).unwrap(),
+            "const DAYS_2024: [Day; 3] = [\n    Day::new(2024, 1, &aoc2024_day1::Day1),\n    Day::new(2024, 19, &aoc2024_day19::Day19),\n    Day::new(2024, 20, &aoc2024_day20::Day20),\n];\n"
+        );
+    }
+
+    #[test]
+    fn a_new_year_gets_its_own_table() {
+        let text = "pub const YEARS: [Year; 1] = [Year::new(2024, &DAYS_2024)];\n\nconst DAYS_2024: [Day; 1] = [\n    Day::new(2024, 1, &aoc2024_day1::Day1),\n];\n\nfn after() {}\n";
+        assert_eq!(
+            register_day(text, 2025, 1).unwrap(),
+            "pub const YEARS: [Year; 2] = [Year::new(2024, &DAYS_2024), Year::new(2025, &DAYS_2025)];\n\nconst DAYS_2024: [Day; 1] = [\n    Day::new(2024, 1, &aoc2024_day1::Day1),\n];\n\nconst DAYS_2025: [Day; 1] = [\n    Day::new(2025, 1, &aoc2025_day1::Day1),\n];\n\nfn after() {}\n"
+        );
+    }
+}
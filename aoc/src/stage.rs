@@ -0,0 +1,15 @@
+use anyhow::{Error, Result};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+// Runs one stage of a day, adding its time to elapsed.
+// A panicking day should not take the rest of the table down with it
+pub fn timed<T>(elapsed: &mut Duration, stage: impl FnOnce() -> Result<T>) -> Result<T> {
+    let t0 = Instant::now();
+    let result =
+        panic::catch_unwind(AssertUnwindSafe(stage)).map_err(|_| Error::msg("solver panicked"))?;
+    *elapsed += Instant::now() - t0;
+    result
+}
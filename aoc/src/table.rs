@@ -0,0 +1,46 @@
+// Plain text table with every column padded to its widest cell
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Table {
+        Table {
+            header: header.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn print(&self) {
+        let mut widths = self.header.iter().map(|h| h.len()).collect::<Vec<usize>>();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        print_row(&self.header, &widths);
+        let rule = widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<String>>();
+        print_row(&rule, &widths);
+        for row in &self.rows {
+            print_row(row, &widths);
+        }
+    }
+}
+
+fn print_row(row: &[String], widths: &[usize]) {
+    let cells = row
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+        .collect::<Vec<String>>();
+    println!("{}", cells.join("  ").trim_end());
+}
@@ -0,0 +1,161 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering::Relaxed},
+};
+
+// The system allocator, counting what it hands out while measure is running. A binary opts in
+// with #[global_allocator], outside of measure it costs one atomic load per call
+pub struct Counting;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+// Can go below 0 when memory from before measure is freed during it
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    // Everything allocated, including memory that was freed again
+    pub bytes: usize,
+    // The most that was allocated and not yet freed at any one time
+    pub peak_live: usize,
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size, Relaxed);
+    let live = LIVE.fetch_add(size as isize, Relaxed) + size as isize;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size as isize, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && COUNTING.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && COUNTING.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if COUNTING.load(Relaxed) {
+            freed(layout.size());
+        }
+        System.dealloc(ptr, layout)
+    }
+
+    // Counted as freeing the old block and allocating the new one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && COUNTING.load(Relaxed) {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Stops counting even when f panics
+struct Stop;
+
+impl Drop for Stop {
+    fn drop(&mut self) {
+        COUNTING.store(false, Relaxed);
+    }
+}
+
+// Runs f and counts what it allocates. The counts are global, so anything else allocating
+// at the same time is counted too, and measures must not overlap. Only the Counting
+// allocator counts, under any other the usage is all zeros
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    ALLOCATIONS.store(0, Relaxed);
+    BYTES.store(0, Relaxed);
+    LIVE.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+    COUNTING.store(true, Relaxed);
+    let stop = Stop;
+    let result = f();
+    drop(stop);
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        peak_live: PEAK.load(Relaxed).max(0) as usize,
+    };
+    (result, usage)
+}
+
+// Bytes with a binary unit, like 1.5 MiB
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_live)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    // Other tests run alongside and may add to the counts, so only lower bounds hold
+    #[test]
+    fn counts_allocations_and_the_peak() {
+        let ((), usage) = measure(|| {
+            for _ in 0..3 {
+                black_box(vec![0u64; 1000]);
+            }
+        });
+        assert!(usage.allocations >= 3);
+        assert!(usage.bytes >= 24000);
+        assert!(usage.peak_live >= 8000);
+
+        let (kept, usage) = measure(|| black_box(vec![0u8; 4096]));
+        assert!(usage.peak_live >= 4096);
+        drop(kept);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
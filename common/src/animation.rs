@@ -0,0 +1,289 @@
+use anyhow::Result;
+use std::{
+    any::Any,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{solution::downcast, Solution};
+
+pub const CONTROLS: &str =
+    "Enter or p: pause/play   n: next   b: back   g N: go to frame N   f N: N frames/s   q: quit";
+
+// One picture of a simulation, usually a grid's text, and a line saying what it shows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub picture: String,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(picture: impl Into<String>, caption: impl Into<String>) -> Frame {
+        Frame {
+            picture: picture.into(),
+            caption: caption.into(),
+        }
+    }
+}
+
+// Days whose solution is a simulation worth watching. The frames start over from the
+// beginning every time this is called, which is how the player seeks backwards
+pub trait Animate: Solution {
+    fn frames(input: &Self::Input) -> Frames<'_>;
+}
+
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+// Object safe view of Animate, like DynSolution. Checks the parsed input's type once and
+// hands back something that starts the frames over each time it is called
+pub trait DynAnimate: Sync {
+    fn frames_dyn<'a>(&self, input: &'a dyn Any) -> Result<Box<dyn Fn() -> Frames<'a> + 'a>>;
+}
+
+impl<S: Animate + Sync> DynAnimate for S {
+    fn frames_dyn<'a>(&self, input: &'a dyn Any) -> Result<Box<dyn Fn() -> Frames<'a> + 'a>> {
+        let input = downcast::<S>(input)?;
+        Ok(Box::new(move || S::frames(input)))
+    }
+}
+
+// A typed line from the terminal. Reading whole lines keeps this free of raw terminal mode
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Control {
+    TogglePause,
+    Step(isize),
+    Seek(usize),
+    Fps(f64),
+    Quit,
+}
+
+impl Control {
+    pub fn parse(line: &str) -> Option<Control> {
+        let mut words = line.split_whitespace();
+        let control = match words.next() {
+            None | Some("p") => Control::TogglePause,
+            Some("n") => Control::Step(1),
+            Some("b") => Control::Step(-1),
+            Some("g") => Control::Seek(words.next()?.parse().ok()?),
+            Some("f") => Control::Fps(words.next()?.parse().ok().filter(|&fps| fps > 0.0)?),
+            Some("q") => Control::Quit,
+            Some(_) => return None,
+        };
+        words.next().is_none().then_some(control)
+    }
+}
+
+// Where playback is and how fast it moves
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Playback {
+    pub frame: usize,
+    pub paused: bool,
+    pub fps: f64,
+}
+
+impl Playback {
+    pub fn new(fps: f64) -> Playback {
+        Playback {
+            frame: 0,
+            paused: false,
+            fps,
+        }
+    }
+
+    // Stepping pauses, so the frame stepped to stays on screen
+    pub fn apply(&mut self, control: Control) {
+        match control {
+            Control::TogglePause => self.paused = !self.paused,
+            Control::Step(by) => {
+                self.frame = self.frame.saturating_add_signed(by);
+                self.paused = true;
+            }
+            Control::Seek(frame) => self.frame = frame,
+            Control::Fps(fps) => self.fps = fps,
+            Control::Quit => {}
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+}
+
+// Gets any frame of a stream, starting it over to go backwards
+struct Cursor<'a, F> {
+    start: F,
+    stream: Frames<'a>,
+    index: usize,
+    current: Option<Frame>,
+}
+
+impl<'a, F> Cursor<'a, F>
+where
+    F: Fn() -> Frames<'a>,
+{
+    fn new(start: F) -> Cursor<'a, F> {
+        let mut stream = start();
+        let current = stream.next();
+        Cursor {
+            start,
+            stream,
+            index: 0,
+            current,
+        }
+    }
+
+    // Moves to the frame, or the last one if the stream ends first. Returns where it ended up
+    fn seek(&mut self, frame: usize) -> usize {
+        if frame < self.index {
+            self.stream = (self.start)();
+            self.current = self.stream.next();
+            self.index = 0;
+        }
+        while self.index < frame {
+            match self.stream.next() {
+                Some(next) => {
+                    self.current = Some(next);
+                    self.index += 1;
+                }
+                None => break,
+            }
+        }
+        self.index
+    }
+}
+
+// Plays frames in the terminal, reading controls from stdin between them. Once stdin is
+// closed the frames just play through to the end
+pub fn play<'a, F>(frames: F, fps: f64) -> io::Result<()>
+where
+    F: Fn() -> Frames<'a>,
+{
+    let controls = read_controls();
+    let mut playback = Playback::new(fps);
+    let mut cursor = Cursor::new(frames);
+    let mut listening = true;
+    let mut out = io::stdout().lock();
+    // Hide the cursor and clear the screen once, then redraw from the top left each frame
+    write!(out, "\x1b[?25l\x1b[2J")?;
+
+    let result = loop {
+        // Asking for a frame past the last one means playback has reached the end
+        let requested = playback.frame;
+        playback.frame = cursor.seek(requested);
+        let at_end = playback.frame < requested;
+        if let Err(e) = draw(&mut out, &cursor, &playback) {
+            break Err(e);
+        }
+        if at_end && !listening {
+            break Ok(());
+        }
+
+        let control = if listening {
+            let wait = if playback.paused || at_end {
+                Duration::from_secs(3600)
+            } else {
+                playback.interval()
+            };
+            match controls.recv_timeout(wait) {
+                Ok(line) => Control::parse(&line),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    listening = false;
+                    playback.paused = false;
+                    None
+                }
+            }
+        } else {
+            thread::sleep(playback.interval());
+            None
+        };
+        match control {
+            Some(Control::Quit) => break Ok(()),
+            Some(control) => playback.apply(control),
+            None if !playback.paused && !at_end => playback.frame += 1,
+            None => {}
+        }
+    };
+
+    write!(out, "\x1b[?25h")?;
+    out.flush()?;
+    result
+}
+
+fn draw<F>(out: &mut impl Write, cursor: &Cursor<'_, F>, playback: &Playback) -> io::Result<()> {
+    write!(out, "\x1b[H")?;
+    if let Some(frame) = &cursor.current {
+        for line in frame.picture.lines() {
+            // Clearing to the end of each line removes what a wider frame left behind
+            write!(out, "{}\x1b[K\r\n", line)?;
+        }
+        write!(
+            out,
+            "\x1b[K\r\nframe {}  {}\x1b[K\r\n",
+            cursor.index, frame.caption
+        )?;
+    }
+    let state = if playback.paused { "paused" } else { "playing" };
+    write!(
+        out,
+        "{}, {} frames/s\x1b[K\r\n{}\x1b[K\r\n\x1b[J",
+        state, playback.fps, CONTROLS
+    )?;
+    out.flush()
+}
+
+fn read_controls() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(count: usize) -> Frames<'static> {
+        Box::new((0..count).map(|i| Frame::new(i.to_string(), "")))
+    }
+
+    #[test]
+    fn controls_parse_from_typed_lines() {
+        assert_eq!(Control::parse(""), Some(Control::TogglePause));
+        assert_eq!(Control::parse(" n "), Some(Control::Step(1)));
+        assert_eq!(Control::parse("g 120"), Some(Control::Seek(120)));
+        assert_eq!(Control::parse("f 2.5"), Some(Control::Fps(2.5)));
+        assert_eq!(Control::parse("f 0"), None);
+        assert_eq!(Control::parse("g"), None);
+        assert_eq!(Control::parse("jump"), None);
+    }
+
+    #[test]
+    fn stepping_back_from_the_start_stays_there() {
+        let mut playback = Playback::new(10.0);
+        playback.apply(Control::Step(-1));
+        assert_eq!(playback.frame, 0);
+        assert!(playback.paused);
+    }
+
+    #[test]
+    fn cursor_seeks_both_ways_and_stops_at_the_end() {
+        let mut cursor = Cursor::new(|| numbered(5));
+        assert_eq!(cursor.seek(3), 3);
+        assert_eq!(cursor.current.as_ref().unwrap().picture, "3");
+        assert_eq!(cursor.seek(1), 1);
+        assert_eq!(cursor.current.as_ref().unwrap().picture, "1");
+        assert_eq!(cursor.seek(10), 4);
+        assert_eq!(cursor.current.as_ref().unwrap().picture, "4");
+    }
+}
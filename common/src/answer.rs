@@ -0,0 +1,189 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+// What a part solves to. Answers compare by how they display, since that is what the puzzle
+// site gets, so Integer(22) equals BigInteger(22) and Coordinate(6, 1) equals Text("6,1")
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Integer(i64),
+    // Only for values past i64, the From impls pick Integer whenever it fits
+    BigInteger(i128),
+    Text(String),
+    Coordinate(i64, i64),
+    // A number backed by the picture that shows it, like the frame with day 14's tree. It
+    // displays as the number, and "{:#}" adds the rows underneath
+    Grid { value: i64, rows: Vec<String> },
+}
+
+impl Answer {
+    pub fn grid(value: i64, picture: &str) -> Answer {
+        Answer::Grid {
+            value,
+            rows: picture.lines().map(str::to_string).collect(),
+        }
+    }
+
+    // The name used for the type field of the JSON output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::BigInteger(_) => "big_integer",
+            Answer::Text(_) => "string",
+            Answer::Coordinate(..) => "coordinate",
+            Answer::Grid { .. } => "grid",
+        }
+    }
+
+    // The value of any answer that is a number, for comparing against too high and too low
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) | Answer::Grid { value, .. } => Some(i128::from(*value)),
+            Answer::BigInteger(value) => Some(*value),
+            Answer::Text(_) | Answer::Coordinate(..) => None,
+        }
+    }
+
+    pub fn rows(&self) -> Option<&[String]> {
+        match self {
+            Answer::Grid { rows, .. } => Some(rows),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+            Answer::Grid { value, rows } => {
+                write!(f, "{}", value)?;
+                if f.alternate() {
+                    for row in rows {
+                        write!(f, "\n{}", row)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        format!("{}", self) == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+// Reads an answer back from text, such as the ledger or an examples manifest. Only text that
+// would display the same way again becomes a number or a coordinate
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Answer, Infallible> {
+        let number = |text: &str| text.parse::<i64>().ok().filter(|n| n.to_string() == text);
+        if let Some(value) = number(text) {
+            return Ok(Answer::Integer(value));
+        }
+        if let Some(value) = text.parse::<i128>().ok().filter(|n| n.to_string() == text) {
+            return Ok(Answer::BigInteger(value));
+        }
+        if let Some((x, y)) = text.split_once(',') {
+            if let (Some(x), Some(y)) = (number(x), number(y)) {
+                return Ok(Answer::Coordinate(x, y));
+            }
+        }
+        Ok(Answer::Text(text.to_string()))
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::BigInteger(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i32, u32, i64, u64, isize, usize, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<(i64, i64)> for Answer {
+    fn from((x, y): (i64, i64)) -> Answer {
+        Answer::Coordinate(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_that_display_the_same_are_equal() {
+        assert_eq!(Answer::from(22u64), Answer::BigInteger(22));
+        assert_eq!(Answer::Coordinate(6, 1), Answer::from("6,1"));
+        assert_eq!(Answer::grid(7, "#.\n.#"), Answer::Integer(7));
+        assert_ne!(Answer::Integer(7), Answer::Text("07".to_string()));
+        assert_eq!(Answer::from(u64::MAX), "18446744073709551615");
+    }
+
+    #[test]
+    fn text_reads_back_as_the_answer_it_came_from() {
+        for answer in [
+            Answer::Integer(-3),
+            Answer::BigInteger(i128::from(i64::MAX) + 1),
+            Answer::Coordinate(6, 1),
+            Answer::from("4,6,3,5,6,3,5,2,1,0"),
+            Answer::from("+5"),
+        ] {
+            let read = answer.to_string().parse::<Answer>().unwrap();
+            assert_eq!(read.kind(), answer.kind());
+            assert_eq!(read, answer);
+        }
+    }
+
+    #[test]
+    fn grids_show_their_rows_when_asked() {
+        let answer = Answer::grid(7, "#.\n.#\n");
+        assert_eq!(answer.to_string(), "7");
+        assert_eq!(format!("{:#}", answer), "7\n#.\n.#");
+        assert_eq!(answer.as_integer(), Some(7));
+    }
+}
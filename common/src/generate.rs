@@ -0,0 +1,29 @@
+use std::fmt::Display;
+
+use crate::{random::Rng, Params};
+
+// A random puzzle input, with the params it has to be solved with
+pub struct Generated {
+    pub text: String,
+    pub params: Params,
+}
+
+impl Generated {
+    pub fn new(text: String) -> Generated {
+        Generated {
+            text,
+            params: Params::new(),
+        }
+    }
+
+    pub fn with_param(mut self, key: &str, value: impl Display) -> Generated {
+        self.params.set(key, value.to_string());
+        self
+    }
+}
+
+// Builds valid inputs for a day. What size counts (lines, grid side, ...) is up to each
+// day, but a bigger size always means more work for the solver
+pub trait Generate {
+    fn generate(rng: &mut Rng, size: usize) -> Generated;
+}
@@ -0,0 +1,217 @@
+use anyhow::{Error, Result};
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{solution::downcast, Frame, Solution};
+
+pub type Rgb = [u8; 3];
+
+// Days with a grid worth looking at as a whole, drawn as text one character per tile
+pub trait Picture: Solution {
+    fn picture(input: &Self::Input) -> Result<String>;
+}
+
+// Object safe view of Picture, like DynSolution
+pub trait DynPicture: Sync {
+    fn picture_dyn(&self, input: &dyn Any) -> Result<String>;
+}
+
+impl<S: Picture + Sync> DynPicture for S {
+    fn picture_dyn(&self, input: &dyn Any) -> Result<String> {
+        S::picture(downcast::<S>(input)?)
+    }
+}
+
+// The colour of each character. Characters without one get a colour made from their
+// code, so every letter of a garden plot map still comes out different
+#[derive(Clone, Debug)]
+pub struct Palette {
+    colours: BTreeMap<char, Rgb>,
+}
+
+impl Default for Palette {
+    fn default() -> Palette {
+        let mut palette = Palette {
+            colours: BTreeMap::new(),
+        };
+        palette.set('.', [20, 20, 28]);
+        palette.set(' ', [20, 20, 28]);
+        palette.set('#', [200, 200, 200]);
+        palette.set('X', [60, 200, 80]);
+        palette.set('O', [230, 180, 40]);
+        palette
+    }
+}
+
+impl Palette {
+    pub fn set(&mut self, ch: char, colour: Rgb) {
+        self.colours.insert(ch, colour);
+    }
+
+    // Reads a C=RRGGBB entry, as given on the command line
+    pub fn set_entry(&mut self, entry: &str) -> Result<()> {
+        let bad = || {
+            Error::msg(format!(
+                "Expected a palette entry like X=ff0000, got {}",
+                entry
+            ))
+        };
+        let (ch, hex) = entry.split_once('=').ok_or_else(bad)?;
+        let mut chars = ch.chars();
+        let (Some(ch), None) = (chars.next(), chars.next()) else {
+            return Err(bad());
+        };
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(bad());
+        }
+        let mut colour = [0; 3];
+        for (i, channel) in colour.iter_mut().enumerate() {
+            *channel = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| bad())?;
+        }
+        self.set(ch, colour);
+        Ok(())
+    }
+
+    pub fn colour(&self, ch: char) -> Rgb {
+        if let Some(&colour) = self.colours.get(&ch) {
+            return colour;
+        }
+        // Spread the code's bits over the channels and keep them bright enough to see
+        let mut hash = (ch as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        let mut colour = [0; 3];
+        for channel in &mut colour {
+            *channel = 64 + (hash % 192) as u8;
+            hash /= 192;
+        }
+        colour
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    // Binary colour, P6
+    Ppm,
+    // Binary greyscale, P5
+    Pgm,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Result<Format> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Ok(Format::Ppm),
+            Some("pgm") => Ok(Format::Pgm),
+            _ => Err(Error::msg(format!(
+                "{}: expected a .ppm or .pgm file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+// Every character becomes a scale by scale square. Short lines are padded with black
+pub fn encode(picture: &str, palette: &Palette, scale: usize, format: Format) -> Vec<u8> {
+    let rows = picture.lines().collect::<Vec<&str>>();
+    let width = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let magic = match format {
+        Format::Ppm => "P6",
+        Format::Pgm => "P5",
+    };
+    let mut bytes =
+        format!("{}\n{} {}\n255\n", magic, width * scale, rows.len() * scale).into_bytes();
+
+    for row in rows {
+        let mut line = Vec::new();
+        let mut chars = row.chars();
+        for _ in 0..width {
+            let colour = chars.next().map_or([0; 3], |ch| palette.colour(ch));
+            for _ in 0..scale {
+                match format {
+                    Format::Ppm => line.extend(colour),
+                    Format::Pgm => line.push(grey(colour)),
+                }
+            }
+        }
+        for _ in 0..scale {
+            bytes.extend(&line);
+        }
+    }
+    bytes
+}
+
+// Perceived brightness, so greyscale keeps the contrast a person sees in colour
+fn grey([r, g, b]: Rgb) -> u8 {
+    ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+}
+
+// The format comes from the extension
+pub fn write(path: &Path, picture: &str, palette: &Palette, scale: usize) -> Result<()> {
+    let bytes = encode(picture, palette, scale, Format::from_path(path)?);
+    fs::write(path, bytes).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+}
+
+// Writes each frame next to path under its index, like robots_00042.ppm for robots.ppm.
+// Returns how many were written
+pub fn write_frames(
+    path: &Path,
+    frames: impl Iterator<Item = (usize, Frame)>,
+    palette: &Palette,
+    scale: usize,
+) -> Result<usize> {
+    Format::from_path(path)?;
+    let mut count = 0;
+    for (index, frame) in frames {
+        write(&numbered(path, index), &frame.picture, palette, scale)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+pub fn numbered(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}_{:05}.{}", stem, index, extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_greyscale_pads_short_rows() {
+        let mut palette = Palette::default();
+        palette.set('#', [255, 255, 255]);
+        palette.set('.', [0, 0, 0]);
+        let bytes = encode("#.#\n#\n", &palette, 2, Format::Pgm);
+        let header = b"P5\n6 4\n255\n";
+        assert_eq!(&bytes[..header.len()], header);
+        let row1 = [255, 255, 0, 0, 255, 255];
+        let row2 = [255, 255, 0, 0, 0, 0];
+        assert_eq!(&bytes[header.len()..], [row1, row1, row2, row2].concat());
+    }
+
+    #[test]
+    fn palette_entries_read_hex_colours() {
+        let mut palette = Palette::default();
+        palette.set_entry("@=ff8000").unwrap();
+        assert_eq!(palette.colour('@'), [255, 128, 0]);
+        assert!(palette.set_entry("@=ff80").is_err());
+        assert!(palette.set_entry("ab=ff8000").is_err());
+        assert_ne!(palette.colour('A'), palette.colour('B'));
+    }
+
+    #[test]
+    fn frames_are_numbered_next_to_the_path() {
+        assert_eq!(
+            numbered(Path::new("/tmp/robots.ppm"), 42),
+            PathBuf::from("/tmp/robots_00042.ppm")
+        );
+    }
+}
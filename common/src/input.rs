@@ -0,0 +1,36 @@
+use anyhow::{Error, Result};
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{parse, Params, Solution};
+
+pub const DEFAULT_INPUT: &str = "puzzle_input.txt";
+
+// Reads and parses the file named by the first command line argument,
+// falling back to puzzle_input.txt when there is none. Any params file
+// next to the input is used too
+pub fn parse_from_args<S: Solution>() -> Result<S::Input> {
+    let path = env::args().nth(1).unwrap_or(DEFAULT_INPUT.to_string());
+    let input = read(&path)?;
+    let params = Params::for_input(Path::new(&path))?;
+    let parsed = S::parse_with(&input, &params).map_err(|e| parse::in_file(e, path))?;
+    params.check_all_read()?;
+    Ok(parsed)
+}
+
+// Reads a whole puzzle input, "-" reads it from stdin instead
+pub fn read(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| Error::msg(format!("stdin: {}", e)))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+}
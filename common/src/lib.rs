@@ -0,0 +1,28 @@
+pub mod allocator;
+pub mod animation;
+mod answer;
+mod generate;
+pub mod image;
+pub mod input;
+pub mod lint;
+pub mod memo;
+pub mod parallel;
+mod params;
+pub mod parse;
+pub mod random;
+pub mod repl;
+pub mod search;
+mod solution;
+pub mod strategy;
+
+pub use animation::{Animate, DynAnimate, Frame, Frames};
+pub use answer::Answer;
+pub use generate::{Generate, Generated};
+pub use image::{DynPicture, Palette, Picture};
+pub use lint::Lint;
+pub use memo::Memo;
+pub use params::{params_path, Params};
+pub use parse::ParseError;
+pub use repl::{DynInspect, Inspect};
+pub use solution::{DynSolution, Solution};
+pub use strategy::Strategy;
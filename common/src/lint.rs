@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, Line},
+    Params, ParseError, Solution,
+};
+
+// Checks an input against the day's grammar. Where parse stops at the first problem, lint
+// carries on and reports all of them
+pub trait Lint: Solution {
+    fn lint(input: &str, params: &Params) -> Vec<ParseError>;
+}
+
+// Runs check on every line, keeping each line's problem
+pub fn each_line<'a, T>(
+    input: &'a str,
+    check: impl FnMut(Line<'a>) -> Result<T, ParseError>,
+) -> Vec<ParseError> {
+    parse::lines(input)
+        .map(check)
+        .filter_map(Result::err)
+        .collect()
+}
+
+// Every character has to pass allowed, every row is as wide as the first and none are blank
+pub fn grid<'a>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    allowed: impl Fn(char) -> bool,
+    expected: &str,
+) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut width = None;
+    for line in lines {
+        for (i, ch) in line.text.char_indices() {
+            if !allowed(ch) {
+                problems.push(line.error(&line.text[i..i + ch.len_utf8()], expected));
+            }
+        }
+        let columns = line.text.chars().count();
+        let &mut (first_line, first_width) = width.get_or_insert((line.number, columns));
+        if columns != first_width {
+            problems.push(line.width_error(first_line, first_width));
+        }
+    }
+    problems
+}
+
+// Each marker appears exactly once, like the guard or the start and end tiles
+pub fn markers(input: &str, markers: &[(char, &str)]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for &(marker, name) in markers {
+        let mut found = parse::lines(input).flat_map(|line| {
+            line.text
+                .match_indices(marker)
+                .map(move |(i, text)| (line, &line.text[i..i + text.len()]))
+        });
+        if found.next().is_none() {
+            problems.push(ParseError::end_of_input(input, name));
+        }
+        for (line, token) in found {
+            problems.push(line.error(token, format!("only one {}", name)));
+        }
+    }
+    problems
+}
+
+// The input split into runs of lines between blank lines. Blank lines at the start, the end
+// or more than one in a row are problems, the grammar only ever wants one between sections
+pub fn sections(input: &str) -> (Vec<Vec<Line<'_>>>, Vec<ParseError>) {
+    let mut sections = vec![Vec::new()];
+    let mut problems = Vec::new();
+    for line in parse::lines(input) {
+        if !line.text.trim().is_empty() {
+            sections.last_mut().expect("always one section").push(line);
+        } else if sections.last().is_some_and(Vec::is_empty) {
+            problems.push(line.error(line.text, "a section, not another blank line"));
+        } else {
+            sections.push(Vec::new());
+        }
+    }
+    if sections.len() > 1 && sections.last().is_some_and(Vec::is_empty) {
+        sections.pop();
+        let last = parse::lines(input).last().expect("a blank line was read");
+        problems.push(last.error(last.text, "a section after the blank line"));
+    }
+    (sections, problems)
+}
+
+// Positions inside a width by height area, optionally each at most once
+pub struct Positions {
+    width: i64,
+    height: i64,
+    unique: bool,
+    first_seen: HashMap<(i64, i64), usize>,
+}
+
+impl Positions {
+    pub fn new(width: i64, height: i64, unique: bool) -> Positions {
+        Positions {
+            width,
+            height,
+            unique,
+            first_seen: HashMap::new(),
+        }
+    }
+
+    // token is the x,y text on the line, for pointing at
+    pub fn check(&mut self, line: &Line, token: &str, x: i64, y: i64) -> Option<ParseError> {
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            let expected = format!(
+                "a position from 0,0 to {},{}",
+                self.width - 1,
+                self.height - 1
+            );
+            return Some(line.error(token, expected));
+        }
+        let first = *self.first_seen.entry((x, y)).or_insert(line.number);
+        (self.unique && first != line.number)
+            .then(|| line.error(token, format!("a position not already on line {}", first)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grids_report_every_bad_character_and_ragged_row() {
+        let problems = grid(
+            parse::lines("#.#\n#x#.\n#y\n"),
+            |ch| "#.".contains(ch),
+            "# or .",
+        );
+        let found = problems
+            .iter()
+            .map(|p| (p.line, p.column, p.found.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [(2, 2, "\"x\""), (2, 4, "4"), (3, 2, "\"y\""), (3, 3, "2")]
+        );
+    }
+
+    #[test]
+    fn markers_must_appear_once() {
+        let input = "S..\n.SE\n";
+        let problems = markers(input, &[('S', "S"), ('E', "E"), ('@', "@")]);
+        assert_eq!(
+            problems,
+            [
+                ParseError::new(2, 2, "only one S", "\"S\""),
+                ParseError::end_of_input(input, "@"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sections_flag_stray_blank_lines() {
+        let (sections, problems) = sections("a\nb\n\n\nc\n\n");
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1][0].text, "c");
+        let lines = problems.iter().map(|p| p.line).collect::<Vec<_>>();
+        assert_eq!(lines, [4, 6]);
+    }
+
+    #[test]
+    fn positions_stay_in_bounds_and_unique() {
+        let input = "1,2\n7,0\n1,2\n";
+        let mut positions = Positions::new(7, 7, true);
+        let problems = parse::lines(input)
+            .filter_map(|line| {
+                let (x, y) = line.text.split_once(',').unwrap();
+                positions.check(&line, line.text, x.parse().unwrap(), y.parse().unwrap())
+            })
+            .map(|p| (p.line, p.expected))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            [
+                (2, "a position from 0,0 to 6,6".to_string()),
+                (3, "a position not already on line 1".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use anyhow::Result;
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::{self, Display},
+    hash::Hash,
+};
+
+use crate::Params;
+
+// Results of a recursive function by argument. Keys can be borrowed from the input, like
+// &str slices of it, so looking one up never allocates
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: Stats,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    // How many times the cache filled up to its limit and was emptied
+    pub clears: usize,
+    pub largest: usize,
+}
+
+// The memo_limit param, unlimited when it is missing or 0
+pub fn limit(params: &Params) -> Result<Option<usize>> {
+    let limit = params.get("memo_limit", 0)?;
+    Ok((limit > 0).then_some(limit))
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo::with_limit(None)
+    }
+
+    // A full cache is emptied before the next insert rather than tracking which entries are
+    // still useful. Answers stay the same, only more of them get worked out again
+    pub fn with_limit(limit: Option<usize>) -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+            limit,
+            stats: Stats::default(),
+        }
+    }
+
+    pub fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let value = self.cache.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        if self.limit.is_some_and(|limit| self.cache.len() >= limit) {
+            self.cache.clear();
+            self.stats.clears += 1;
+        }
+        if self.limit != Some(0) {
+            self.cache.insert(key, value.clone());
+        }
+        self.stats.largest = self.stats.largest.max(self.cache.len());
+        value
+    }
+
+    // compute gets the memo back so it can recurse through it
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        match self.get(&key) {
+            Some(value) => value,
+            None => {
+                let value = compute(self);
+                self.insert(key, value)
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Memo::new()
+    }
+}
+
+impl Stats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit), at most {} cached",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.largest
+        )?;
+        if self.clears > 0 {
+            write!(f, ", cleared {} times", self.clears)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(50, &mut memo), 12586269025);
+        // Each n from 2 to 50 is worked out once, and found again when n + 2 asks for it
+        let stats = memo.stats();
+        assert_eq!((stats.misses, stats.hits, stats.largest), (49, 47, 49));
+        assert_eq!(memo.len(), 49);
+        fibonacci(50, &mut memo);
+        assert_eq!(memo.stats().hits, 48);
+    }
+
+    #[test]
+    fn limit_bounds_the_cache_but_not_the_answer() {
+        let mut memo = Memo::with_limit(Some(10));
+        assert_eq!(fibonacci(50, &mut memo), 12586269025);
+        let stats = memo.stats();
+        assert!(stats.largest <= 10 && stats.clears > 0);
+        assert!(memo.len() <= 10);
+
+        let mut nothing = Memo::with_limit(Some(0));
+        assert_eq!(fibonacci(20, &mut nothing), 6765);
+        assert!(nothing.is_empty());
+    }
+
+    #[test]
+    fn owned_keys_are_found_by_borrowed_ones() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        assert_eq!(memo.get("abc"), None);
+        memo.insert("abc".to_string(), 3);
+        assert_eq!(memo.get("abc"), Some(3));
+        assert_eq!(
+            memo.stats().to_string(),
+            "1 hits, 1 misses (50.0% hit), at most 1 cached"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    cell::Cell,
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+// How many threads map may use, 0 until set_jobs is called
+static JOBS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Set on map's threads, so a solver's own map inside a day the runner is already
+    // running in parallel doesn't start a second set of threads on top
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn set_jobs(jobs: NonZeroUsize) {
+    JOBS.store(jobs.get(), Ordering::Relaxed);
+}
+
+// Every core unless set_jobs said otherwise
+pub fn jobs() -> usize {
+    match JOBS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        jobs => jobs,
+    }
+}
+
+// Calls f on every item across up to jobs() threads, each taking the next item as soon as
+// it is free, and returns the results in the items' order
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = jobs().min(items.len());
+    if threads <= 1 || IN_WORKER.get() {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut done = thread::scope(|scope| {
+        let workers = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    IN_WORKER.set(true);
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break done;
+                        };
+                        done.push((index, f(item)));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect::<Vec<(usize, R)>>()
+    });
+    done.sort_unstable_by_key(|&(index, _)| index);
+    done.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_the_items_order() {
+        let items = (0..1000u64).collect::<Vec<u64>>();
+        let squares = map(&items, |&x| {
+            // Uneven work so the threads finish out of order
+            thread::sleep(std::time::Duration::from_micros(x % 7));
+            x * x
+        });
+        assert_eq!(squares, items.iter().map(|x| x * x).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn nested_maps_run_on_the_outer_threads() {
+        let same_thread = map(&[0, 1, 2, 3], |_| {
+            let outer = thread::current().id();
+            map(&[0, 1, 2, 3], |_| thread::current().id())
+                .iter()
+                .all(|&inner| inner == outer)
+        });
+        assert!(same_thread.iter().all(|&same| same));
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{Error, Result};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Mutex, MutexGuard},
+};
+
+// Named values that change how a day is solved, like the room size for an example input
+#[derive(Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+    // Every key a day has asked for, so ones it never reads can be caught as typos
+    read: Mutex<BTreeSet<String>>,
+}
+
+impl Clone for Params {
+    fn clone(&self) -> Params {
+        Params {
+            values: self.values.clone(),
+            read: Mutex::new(self.read().clone()),
+        }
+    }
+}
+
+// Which keys were read is bookkeeping, two params with the same values are the same
+impl PartialEq for Params {
+    fn eq(&self, other: &Params) -> bool {
+        self.values == other.values
+    }
+}
+
+impl Eq for Params {}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    // Reads the params file that goes with an input, which is the input's path with a
+    // .params extension. Inputs without one (and stdin) just use every default
+    pub fn for_input(input: &Path) -> Result<Params> {
+        let path = params_path(input);
+        if input == Path::new("-") || !path.exists() {
+            return Ok(Params::new());
+        }
+        let text = fs::read_to_string(&path)?;
+        text.parse()
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    // Layers other on top of these params, its values win
+    pub fn extend(&mut self, other: &Params) {
+        for (key, value) in other.iter() {
+            self.set(key, value);
+        }
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.values.remove(key)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    // The value for key, or the default when it was never set
+    pub fn get<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.read().insert(key.to_string());
+        match self.values.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| Error::msg(format!("Bad value for {}: {:?} ({})", key, value, e))),
+            None => Ok(default),
+        }
+    }
+
+    // Fails on a key that was set but never asked for by get, which is most likely a typo.
+    // Only meaningful once the day has parsed with these params
+    fn read(&self) -> MutexGuard<'_, BTreeSet<String>> {
+        self.read.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn check_all_read(&self) -> Result<()> {
+        let read = self.read();
+        let unread = self
+            .values
+            .keys()
+            .filter(|key| !read.contains(*key))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if unread.is_empty() {
+            return Ok(());
+        }
+        let known = match read.len() {
+            0 => "this day takes no params".to_string(),
+            _ => format!(
+                "this day reads {}",
+                read.iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        Err(Error::msg(format!(
+            "Unknown param {}, {}",
+            unread.join(", "),
+            known
+        )))
+    }
+}
+
+// One key = value per line, blank lines and lines starting with # are skipped
+impl FromStr for Params {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Params> {
+        let mut params = Params::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::msg(format!("line {}: expected key = value", i + 1)))?;
+            params.set(key.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+pub fn params_path(input: &Path) -> PathBuf {
+    input.with_extension("params")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_that_are_never_read_are_rejected() {
+        let params = "byte_cuont = 12\ngrid_size = 7\n"
+            .parse::<Params>()
+            .unwrap();
+        assert_eq!(params.get("grid_size", 71).unwrap(), 7);
+        assert_eq!(params.get("byte_count", 1024).unwrap(), 1024);
+        assert_eq!(
+            params.check_all_read().unwrap_err().to_string(),
+            "Unknown param byte_cuont, this day reads byte_count, grid_size"
+        );
+        assert!(Params::new().check_all_read().is_ok());
+    }
+}
@@ -0,0 +1,276 @@
+use std::{error::Error, fmt, str::FromStr};
+
+// Where and why an input could not be parsed. Lines and columns count from 1,
+// the file is only known once the error reaches whoever read the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    // For things that are missing altogether, points just past the last line
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(input.lines().count() + 1, 1, expected, "end of input")
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// Adds the file name to a parse error that went through anyhow, other errors pass through
+pub fn in_file(error: anyhow::Error, file: impl Into<String>) -> anyhow::Error {
+    match error.downcast::<ParseError>() {
+        Ok(parse_error) => parse_error.in_file(file).into(),
+        Err(error) => error,
+    }
+}
+
+// One line of the input that knows its line number, so errors can point into it
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+// The input split into runs of lines between blank lines, however many blank lines there are.
+// lint::sections is the strict version
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if !line.text.trim().is_empty() {
+            sections.last_mut().expect("always one section").push(line);
+        } else if sections.last().is_some_and(|section| !section.is_empty()) {
+            sections.push(Vec::new());
+        }
+    }
+    sections.retain(|section| !section.is_empty());
+    sections
+}
+
+// For inputs that are one section, a blank line, then another. expected describes what is
+// missing when the input stops early
+pub fn two_sections<'a>(
+    input: &'a str,
+    expected: &str,
+) -> Result<(Vec<Line<'a>>, Vec<Line<'a>>), ParseError> {
+    let mut sections = sections(input).into_iter();
+    match (sections.next(), sections.next(), sections.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        (_, _, Some(extra)) => Err(extra[0].error(extra[0].text, "end of input")),
+        _ => Err(ParseError::end_of_input(input, expected)),
+    }
+}
+
+// The rows of a character grid. Every character has to pass allowed and every row has to be
+// as wide as the first
+pub fn char_grid<'a>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    allowed: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut rows = Vec::new();
+    let mut first = None;
+    for line in lines {
+        let row = line.checked_chars(&allowed, expected)?;
+        let &mut (first_line, width) = first.get_or_insert((line.number, row.len()));
+        if row.len() != width {
+            return Err(line.width_error(first_line, width));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+impl<'a> Line<'a> {
+    // token should be a slice of this line, anything else is reported at the end of the line
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(self.text.len());
+        let column = self.text[..offset].chars().count() + 1;
+        let found = if token.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", token)
+        };
+        ParseError::new(self.number, column, expected, found)
+    }
+
+    // The empty slice after the last character, for reporting something missing
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    pub fn number<T: FromStr>(&self, token: &'a str) -> Result<T, ParseError> {
+        token
+            .trim()
+            .parse()
+            .map_err(|_| self.error(token.trim(), "a number"))
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(&text[text.len()..], format!("{:?}", separator)))
+    }
+
+    pub fn strip_prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix).ok_or_else(|| {
+            // Point at the first character that differs
+            let mismatch = text
+                .char_indices()
+                .zip(prefix.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(text.len(), |((i, _), _)| i);
+            let found = text[mismatch..].split_whitespace().next().unwrap_or("");
+            self.error(found, format!("{:?}", prefix))
+        })
+    }
+
+    // For a grid row that is not as wide as the first_line row, pointing where they differ
+    pub fn width_error(&self, first_line: usize, width: usize) -> ParseError {
+        let at = self
+            .text
+            .char_indices()
+            .nth(width)
+            .map_or(self.text.len(), |(i, _)| i);
+        ParseError::new(
+            self.number,
+            self.text[..at].chars().count() + 1,
+            format!("{} columns like line {}", width, first_line),
+            self.text.chars().count().to_string(),
+        )
+    }
+
+    // Every character of the line, each of which has to pass allowed
+    pub fn checked_chars(
+        &self,
+        allowed: impl Fn(char) -> bool,
+        expected: &str,
+    ) -> Result<Vec<char>, ParseError> {
+        let mut row = Vec::new();
+        for (i, ch) in self.text.char_indices() {
+            if !allowed(ch) {
+                return Err(self.error(&self.text[i..i + ch.len_utf8()], expected));
+            }
+            row.push(ch);
+        }
+        Ok(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_the_token() {
+        let line = lines("1,2\np=3,x4").nth(1).unwrap();
+        let (_, y) = line.split_once(line.text, ",").unwrap();
+        let error = line.number::<i32>(y).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 5, "a number", "\"x4\""));
+        assert_eq!(
+            error.in_file("day14.txt").to_string(),
+            "day14.txt:2:5: expected a number, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn missing_separator_points_at_end_of_line() {
+        let line = lines("12").next().unwrap();
+        let error = line.split_once(line.text, ",").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 3, "\",\"", "end of line"));
+    }
+
+    #[test]
+    fn wrong_prefix_points_at_the_difference() {
+        let line = lines("Button A: X+94").next().unwrap();
+        let error = line.strip_prefix(line.text, "Button B: ").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 8, "\"Button B: \"", "\"A:\""));
+    }
+
+    #[test]
+    fn sections_skip_any_number_of_blank_lines() {
+        let input = "\na\nb\n\n\nc\n  \n";
+        let sections = sections(input);
+        let texts = sections
+            .iter()
+            .map(|section| section.iter().map(|line| line.text).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, [vec!["a", "b"], vec!["c"]]);
+        assert_eq!(sections[1][0].number, 6);
+
+        let error = two_sections("a\n\nb\n\nc", "the moves").unwrap_err();
+        assert_eq!(error, ParseError::new(5, 1, "end of input", "\"c\""));
+        let error = two_sections("a\n", "the moves").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "the moves", "end of input"));
+    }
+
+    #[test]
+    fn char_grid_rows_match_the_first() {
+        let rows = char_grid(lines("#.\n.#"), |ch| "#.".contains(ch), "# or .").unwrap();
+        assert_eq!(rows, [vec!['#', '.'], vec!['.', '#']]);
+        let error = char_grid(lines("#.\n.#.#"), |ch| "#.".contains(ch), "# or .").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "2 columns like line 1", "4"));
+    }
+
+    #[test]
+    fn checked_chars_reject_unknown_characters() {
+        let line = lines("#.O\n#.x#").nth(1).unwrap();
+        let error = line
+            .checked_chars(|ch| "#.O".contains(ch), "a wall, box or space")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(2, 3, "a wall, box or space", "\"x\"")
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use anyhow::{Error, Result};
+use std::{
+    any::Any,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{solution::downcast, DynSolution, Solution};
+
+// One command a day adds to the REPL. run gets the words typed after the name
+pub struct Command<S: Inspect> {
+    pub name: &'static str,
+    // The name and its arguments, like "cell ROW COL"
+    pub usage: &'static str,
+    pub help: &'static str,
+    pub run: Run<S>,
+}
+
+pub type Run<S> =
+    fn(&<S as Solution>::Input, &mut <S as Inspect>::State, &[&str]) -> Result<String>;
+
+// Days that can be poked at from the REPL
+pub trait Inspect: Solution + Sized {
+    // Whatever the commands change, like a simulation part way through.
+    // Starts over from the parsed input on reset
+    type State;
+
+    fn state(input: &Self::Input) -> Self::State;
+    fn commands() -> Vec<Command<Self>>;
+}
+
+// A day's commands with their input and state, the types erased
+pub trait Session {
+    fn usage(&self) -> Vec<(&'static str, &'static str)>;
+    // None when the name is not one of the day's commands
+    fn run(&mut self, name: &str, args: &[&str]) -> Option<Result<String>>;
+    fn reset(&mut self);
+}
+
+// Object safe view of Inspect, like DynSolution
+pub trait DynInspect: Sync {
+    fn session<'a>(&self, input: &'a dyn Any) -> Result<Box<dyn Session + 'a>>;
+}
+
+impl<S: Inspect + Sync + 'static> DynInspect for S {
+    fn session<'a>(&self, input: &'a dyn Any) -> Result<Box<dyn Session + 'a>> {
+        let input = downcast::<S>(input)?;
+        Ok(Box::new(TypedSession::<S> {
+            input,
+            state: S::state(input),
+            commands: S::commands(),
+        }))
+    }
+}
+
+struct TypedSession<'a, S: Inspect> {
+    input: &'a S::Input,
+    state: S::State,
+    commands: Vec<Command<S>>,
+}
+
+impl<S: Inspect> Session for TypedSession<'_, S> {
+    fn usage(&self) -> Vec<(&'static str, &'static str)> {
+        self.commands.iter().map(|c| (c.usage, c.help)).collect()
+    }
+
+    fn run(&mut self, name: &str, args: &[&str]) -> Option<Result<String>> {
+        let command = self.commands.iter().find(|c| c.name == name)?;
+        Some((command.run)(self.input, &mut self.state, args))
+    }
+
+    fn reset(&mut self) {
+        self.state = S::state(self.input);
+    }
+}
+
+const BUILT_IN: [(&str, &str); 5] = [
+    ("part1", "solve part 1 from the parsed input"),
+    ("part2", "solve part 2 from the parsed input"),
+    ("reset", "start the day's state over"),
+    ("help", "list the commands"),
+    ("quit", "leave, so does end of input"),
+];
+
+// Reads commands a line at a time until quit or the end of input. A command that fails
+// prints its error and the REPL carries on
+pub fn run(
+    solution: &dyn DynSolution,
+    input: &dyn Any,
+    mut session: Option<Box<dyn Session + '_>>,
+    prompt: &str,
+    reader: impl BufRead,
+    mut out: impl Write,
+) -> Result<()> {
+    write!(out, "{}", prompt)?;
+    out.flush()?;
+    for line in reader.lines() {
+        let line = line?;
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let result = match words.as_slice() {
+            [] => Ok(String::new()),
+            ["quit" | "exit", ..] => break,
+            // Alternate so a grid answer shows its rows
+            ["part1"] => solution
+                .part1_dyn(input)
+                .map(|answer| format!("{:#}", answer)),
+            ["part2"] => solution
+                .part2_dyn(input)
+                .map(|answer| format!("{:#}", answer)),
+            ["reset"] => {
+                session.iter_mut().for_each(|session| session.reset());
+                Ok(String::new())
+            }
+            ["help"] => Ok(help(session.as_deref())),
+            [name, args @ ..] => session
+                .as_mut()
+                .and_then(|session| session.run(name, args))
+                .unwrap_or_else(|| Err(Error::msg(format!("Unknown command {}, try help", name)))),
+        };
+        match result {
+            Ok(text) if text.is_empty() => {}
+            Ok(text) => writeln!(out, "{}", text.trim_end())?,
+            Err(e) => writeln!(out, "error: {}", e)?,
+        }
+        write!(out, "{}", prompt)?;
+        out.flush()?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+fn help(session: Option<&(dyn Session + '_)>) -> String {
+    let day = session.map(|session| session.usage()).unwrap_or_default();
+    let width = BUILT_IN
+        .iter()
+        .chain(&day)
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    BUILT_IN
+        .iter()
+        .chain(&day)
+        .map(|(usage, help)| format!("{:width$}  {}\n", usage, help, width = width))
+        .collect()
+}
+
+// The argument at index, or default when it was left out
+pub fn arg<T: FromStr>(args: &[&str], index: usize, name: &str, default: Option<T>) -> Result<T> {
+    match args.get(index) {
+        Some(text) => text
+            .parse()
+            .map_err(|_| Error::msg(format!("Expected {}, got {}", name, text))),
+        None => default.ok_or_else(|| Error::msg(format!("Expected {}", name))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+    use std::io::Cursor;
+
+    // Sums numbers, with a running counter to poke at
+    struct Counter;
+
+    impl Solution for Counter {
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input
+                .split_whitespace()
+                .map(|n| n.parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Answer> {
+            Ok(numbers.iter().sum::<u64>().into())
+        }
+
+        fn part2(_: &Self::Input) -> Result<Answer> {
+            Err(Error::msg("not yet"))
+        }
+    }
+
+    impl Inspect for Counter {
+        type State = u64;
+
+        fn state(_: &Self::Input) -> u64 {
+            0
+        }
+
+        fn commands() -> Vec<Command<Self>> {
+            vec![Command {
+                name: "add",
+                usage: "add [N]",
+                help: "add the Nth number, the first by default",
+                run: |numbers, total, args| {
+                    let index = arg(args, 0, "a number index", Some(0))?;
+                    *total += numbers
+                        .get(index)
+                        .ok_or_else(|| Error::msg("no such number"))?;
+                    Ok(total.to_string())
+                },
+            }]
+        }
+    }
+
+    fn session(commands: &str) -> String {
+        let input = Counter::parse("3 4").unwrap();
+        let mut out = Vec::new();
+        let session = Counter.session(&input).unwrap();
+        run(
+            &Counter,
+            &input,
+            Some(session),
+            "> ",
+            Cursor::new(commands),
+            &mut out,
+        )
+        .unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn commands_share_state_until_reset() {
+        let out = session("part1\nadd 1\nadd\nreset\nadd\nquit\nadd\n");
+        assert_eq!(out, "> 7\n> 4\n> 7\n> > 3\n> \n");
+    }
+
+    #[test]
+    fn errors_are_reported_and_the_repl_carries_on() {
+        let out = session("part2\nadd x\nadd 5\njump\npart1\n");
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "> error: not yet",
+                "> error: Expected a number index, got x",
+                "> error: no such number",
+                "> error: Unknown command jump, try help",
+                "> 7",
+                "> ",
+            ]
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+// Anything that adds up like a path length. Zero is the default
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+// What a search found: the best cost of every state it settled and, for each, every
+// state it can be reached from on a best path. Costs are expected to be positive
+pub struct Search<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    // States in the order they were settled, so by distance
+    order: Vec<S>,
+}
+
+impl<S: Copy + Eq + Hash, C: Cost> Search<S, C> {
+    fn new(start: S) -> Search<S, C> {
+        Search {
+            start,
+            distances: HashMap::from([(start, C::default())]),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    // Keeps from if it ties the best cost, or replaces the others if it beats it.
+    // True when the cost improved
+    fn relax(&mut self, from: S, to: S, cost: C) -> bool {
+        match self.distances.get(&to) {
+            Some(&best) if cost > best => false,
+            Some(&best) if cost == best => {
+                let predecessors = self.predecessors.entry(to).or_default();
+                if !predecessors.contains(&from) {
+                    predecessors.push(from);
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(to, cost);
+                self.predecessors.insert(to, vec![from]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn order(&self) -> &[S] {
+        &self.order
+    }
+
+    // One best path from the start to goal, both included
+    pub fn path_to(&self, goal: S) -> Option<Vec<S>> {
+        self.distances.get(&goal)?;
+        let mut path = vec![goal];
+        while let Some(&previous) = self.predecessors(path.last()?).first() {
+            path.push(previous);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Every state on at least one best path to any of the goals
+    pub fn on_best_paths(&self, goals: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut found = HashSet::new();
+        let mut stack = goals
+            .into_iter()
+            .filter(|goal| self.distances.contains_key(goal))
+            .collect::<Vec<S>>();
+        while let Some(state) = stack.pop() {
+            if found.insert(state) {
+                stack.extend(self.predecessors(&state));
+            }
+        }
+        found
+    }
+
+    // How many different best paths lead from the start to goal
+    pub fn path_count(&self, goal: &S) -> u64 {
+        let mut counts = HashMap::from([(self.start, 1)]);
+        // A state is counted once all its predecessors are, which it goes back on the stack for
+        let mut stack = vec![(*goal, false)];
+        while let Some((state, ready)) = stack.pop() {
+            if counts.contains_key(&state) {
+                continue;
+            }
+            let predecessors = self.predecessors(&state);
+            if ready {
+                let count = predecessors.iter().map(|p| counts[p]).sum::<u64>();
+                counts.insert(state, count);
+            } else {
+                stack.push((state, true));
+                stack.extend(predecessors.iter().map(|&p| (p, false)));
+            }
+        }
+        counts.get(goal).copied().unwrap_or(0)
+    }
+}
+
+// Every step costs one, so a queue settles states in order
+pub fn bfs<S, N, I>(start: S, mut neighbours: N) -> Search<S, usize>
+where
+    S: Copy + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        search.order.push(state);
+        let next_cost = search.distances[&state] + 1;
+        for next in neighbours(&state) {
+            if search.relax(state, next, next_cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+// Settles every state reachable from start
+pub fn dijkstra<S, C, N, I>(start: S, neighbours: N) -> Search<S, C>
+where
+    S: Copy + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, neighbours, |_| C::default(), |_| false)
+}
+
+// Stops once nothing left can reach a goal as cheaply as the best one found, which still
+// settles every best path to it. The heuristic must never overestimate the cost left, and
+// should never drop by more than a step's cost from one state to the next
+pub fn astar<S, C, N, I, H, G>(start: S, neighbours: N, heuristic: H, is_goal: G) -> Search<S, C>
+where
+    S: Copy + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    best_first(start, neighbours, heuristic, is_goal)
+}
+
+fn best_first<S, C, N, I, H, G>(
+    start: S,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<S, C>
+where
+    S: Copy + Eq + Hash,
+    C: Cost,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start);
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut goal_cost = None;
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if goal_cost.is_some_and(|goal| priority > goal) {
+            break;
+        }
+        // Left behind when a cheaper way in was found later
+        if cost > search.distances[&state] {
+            continue;
+        }
+        search.order.push(state);
+        if is_goal(&state) {
+            goal_cost.get_or_insert(cost);
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if search.relax(state, next, next_cost) {
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+// Orders the heap by priority alone, smallest first, so states need no ordering
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 3 by 3 grid of points, every step right or down
+    fn right_or_down(&(x, y): &(u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| x < 3 && y < 3)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_keeps_every_best_path() {
+        let search = bfs((0, 0), right_or_down);
+        assert_eq!(search.distance(&(2, 2)), Some(4));
+        assert_eq!(search.path_count(&(2, 2)), 6);
+        assert_eq!(search.on_best_paths([(2, 2)]).len(), 9);
+        assert_eq!(search.on_best_paths([(1, 0)]).len(), 2);
+        let path = search.path_to((2, 2)).unwrap();
+        assert_eq!((path[0], path[4]), ((0, 0), (2, 2)));
+    }
+
+    #[test]
+    fn cheaper_detours_win() {
+        // Straight from a to c costs 10, going through b costs 2
+        let edges = |state: &char| match state {
+            'a' => vec![('c', 10), ('b', 1)],
+            'b' => vec![('c', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra('a', edges);
+        assert_eq!(search.distance(&'c'), Some(2));
+        assert_eq!(search.path_to('c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(search.path_to('d'), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra_and_settles_less() {
+        let weighted = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..20).contains(&x) && (0..20).contains(&y))
+                .map(|(x, y)| ((x, y), 1 + (x * 7 + y * 13) % 3))
+                .collect::<Vec<_>>()
+        };
+        let goal = (10, 3);
+        let full = dijkstra((0, 0), weighted);
+        let directed = astar(
+            (0, 0),
+            weighted,
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&state| state == goal,
+        );
+        assert_eq!(directed.distance(&goal), full.distance(&goal));
+        assert_eq!(directed.path_count(&goal), full.path_count(&goal));
+        assert!(directed.order().len() < full.order().len());
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{Error, Result};
+use std::any::Any;
+
+use crate::{Answer, Params};
+
+// A day's puzzle split into its stages, so parsing can be timed and
+// tested apart from solving
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    // Days with tunable values read them here, the rest just parse
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+// Object safe view of a Solution, so days with different input types can sit
+// in one table. The parsed input is handed back as an opaque box.
+pub trait DynSolution: Sync {
+    fn parse_dyn(&self, input: &str, params: &Params) -> Result<Box<dyn Any>>;
+    fn part1_dyn(&self, input: &dyn Any) -> Result<Answer>;
+    fn part2_dyn(&self, input: &dyn Any) -> Result<Answer>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse_dyn(&self, input: &str, params: &Params) -> Result<Box<dyn Any>> {
+        let parsed = S::parse_with(input, params)?;
+        params.check_all_read()?;
+        Ok(Box::new(parsed))
+    }
+
+    fn part1_dyn(&self, input: &dyn Any) -> Result<Answer> {
+        S::part1(downcast::<S>(input)?)
+    }
+
+    fn part2_dyn(&self, input: &dyn Any) -> Result<Answer> {
+        S::part2(downcast::<S>(input)?)
+    }
+}
+
+pub(crate) fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref()
+        .ok_or_else(|| Error::msg("Parsed input belongs to a different day"))
+}
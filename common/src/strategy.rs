@@ -0,0 +1,58 @@
+use anyhow::{Error, Result};
+
+use crate::{Answer, Params, Solution};
+
+// A day's interchangeable algorithms for the same answer. The `strategy` param picks one
+// by name, and without it the first one in ALL is used
+pub trait Strategy: Copy + 'static {
+    const ALL: &'static [Self];
+
+    fn name(self) -> &'static str;
+
+    fn from_params(params: &Params) -> Result<Self> {
+        let default = Self::ALL[0].name().to_string();
+        let name = params.get("strategy", default)?;
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|strategy| strategy.name() == name)
+            .ok_or_else(|| {
+                let names = Self::ALL.iter().map(|s| s.name()).collect::<Vec<_>>();
+                Error::msg(format!(
+                    "Unknown strategy {:?}, expected one of {}",
+                    name,
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+// Solves the input with every strategy and fails unless they all give the same answers
+pub fn check_agreement<S, T>(input: &str, params: &Params) -> Result<[Answer; 2]>
+where
+    S: Solution,
+    T: Strategy,
+{
+    let mut first: Option<(&str, [Answer; 2])> = None;
+    for strategy in T::ALL {
+        let mut params = params.clone();
+        params.set("strategy", strategy.name());
+        let parsed = S::parse_with(input, &params)?;
+        let answers = [S::part1(&parsed)?, S::part2(&parsed)?];
+        match &first {
+            None => first = Some((strategy.name(), answers)),
+            Some((first_name, first_answers)) if *first_answers != answers => {
+                return Err(Error::msg(format!(
+                    "{} gave {:?} but {} gave {:?} for:\n{}",
+                    first_name,
+                    first_answers,
+                    strategy.name(),
+                    answers,
+                    input
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(first.expect("A strategy list is never empty").1)
+}
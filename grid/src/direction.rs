@@ -0,0 +1,73 @@
+use crate::GridPoint;
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn step(&self) -> GridPoint {
+        match self {
+            Direction::Up => GridPoint::new(-1, 0),
+            Direction::Right => GridPoint::new(0, 1),
+            Direction::Down => GridPoint::new(1, 0),
+            Direction::Left => GridPoint::new(0, -1),
+        }
+    }
+
+    // Clockwise
+    pub fn turn(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    // Counter-clockwise
+    pub fn turn_back(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_undo_each_other() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn().turn_back(), direction);
+            assert_eq!(direction.turn().turn(), direction.opposite());
+            assert_eq!(
+                direction.step() + direction.opposite().step(),
+                GridPoint::new(0, 0)
+            );
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt::{self, Display};
+
+use crate::GridPoint;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T = char> {
+    grid: Vec<Vec<T>>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(grid: Vec<Vec<T>>) -> Grid<T> {
+        Grid { grid }
+    }
+
+    pub fn filled(bounds: (usize, usize), val: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(vec![vec![val; bounds.1]; bounds.0])
+    }
+
+    // (rows, columns) - every row is assumed to be as wide as the first
+    pub fn get_bounds(&self) -> (usize, usize) {
+        (
+            self.grid.len(),
+            self.grid.first().map_or(0, |row| row.len()),
+        )
+    }
+
+    pub fn get(&self, location: &GridPoint) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.get_ref(location).copied()
+    }
+
+    pub fn get_ref(&self, location: &GridPoint) -> Option<&T> {
+        if location.index1 < 0 || location.index2 < 0 {
+            // Out of Bounds
+            return None;
+        }
+        self.grid
+            .get(location.index1 as usize)
+            .and_then(|row| row.get(location.index2 as usize))
+    }
+
+    // Returns false without changing anything if the location is out of bounds
+    pub fn set(&mut self, location: &GridPoint, val: T) -> bool {
+        if location.index1 < 0 || location.index2 < 0 {
+            return false;
+        }
+        match self
+            .grid
+            .get_mut(location.index1 as usize)
+            .and_then(|row| row.get_mut(location.index2 as usize))
+        {
+            Some(cell) => {
+                *cell = val;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn find(&self, val: T) -> Option<GridPoint>
+    where
+        T: PartialEq,
+    {
+        self.points_with(|v| *v == val).next()
+    }
+
+    pub fn find_all(&self, val: T) -> Vec<GridPoint>
+    where
+        T: PartialEq,
+    {
+        self.points_with(|v| *v == val).collect()
+    }
+
+    // Every location whose value satisfies the predicate, row by row
+    pub fn points_with<'a, F>(&'a self, mut predicate: F) -> impl Iterator<Item = GridPoint> + 'a
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter_map(move |(gp, v)| if predicate(v) { Some(gp) } else { None })
+    }
+
+    // Every location in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = GridPoint> + '_ {
+        self.iter().map(|(gp, _)| gp)
+    }
+
+    // Every location paired with its value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (GridPoint, &T)> {
+        self.grid.iter().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(j, v)| (GridPoint::new(i as i32, j as i32), v))
+        })
+    }
+
+    // The in-bounds points sharing a side with the location, with their values
+    pub fn neighbours<'a>(
+        &'a self,
+        location: &'a GridPoint,
+    ) -> impl Iterator<Item = (GridPoint, &'a T)> + 'a {
+        location
+            .neighbours()
+            .filter_map(|gp| self.get_ref(&gp).map(|v| (gp, v)))
+    }
+
+    // The in-bounds points sharing a side or a corner with the location, with their values
+    pub fn all_neighbours<'a>(
+        &'a self,
+        location: &'a GridPoint,
+    ) -> impl Iterator<Item = (GridPoint, &'a T)> + 'a {
+        location
+            .all_neighbours()
+            .filter_map(|gp| self.get_ref(&gp).map(|v| (gp, v)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.grid.iter().map(|row| row.as_slice())
+    }
+
+    pub fn row(&self, index1: usize) -> Option<&[T]> {
+        self.grid.get(index1).map(|row| row.as_slice())
+    }
+
+    pub fn column(&self, index2: usize) -> impl Iterator<Item = &T> {
+        self.grid.iter().filter_map(move |row| row.get(index2))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.get_bounds().1).map(|index2| self.column(index2))
+    }
+
+    pub fn display(&self)
+    where
+        T: Display,
+    {
+        print!("{}", self);
+    }
+}
+
+// One line per row, the same layout the puzzle inputs use
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.grid {
+            for val in row {
+                write!(f, "{}", val)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // abc
+    // def
+    // ghi
+    fn letters() -> Grid {
+        Grid::new(
+            ["abc", "def", "ghi"]
+                .map(|row| row.chars().collect())
+                .to_vec(),
+        )
+    }
+
+    fn values<'a>(cells: impl Iterator<Item = (GridPoint, &'a char)>) -> String {
+        cells.map(|(_, v)| *v).collect()
+    }
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = letters();
+        assert_eq!(values(grid.neighbours(&GridPoint::new(0, 0))), "bd");
+        assert_eq!(values(grid.neighbours(&GridPoint::new(1, 2))), "cie");
+        assert_eq!(values(grid.neighbours(&GridPoint::new(1, 1))), "bfhd");
+        assert_eq!(values(grid.all_neighbours(&GridPoint::new(0, 0))), "bed");
+        assert_eq!(values(grid.all_neighbours(&GridPoint::new(2, 1))), "efigd");
+        assert_eq!(
+            values(grid.all_neighbours(&GridPoint::new(1, 1))),
+            "bcfihgda"
+        );
+        assert_eq!(grid.neighbours(&GridPoint::new(-1, 0)).count(), 1);
+    }
+
+    #[test]
+    fn get_and_set_ignore_points_off_the_grid() {
+        let mut grid = letters();
+        for point in [(-1, 0), (0, -1), (3, 0), (0, 3)] {
+            let point = GridPoint::new(point.0, point.1);
+            assert_eq!(grid.get(&point), None);
+            assert!(!grid.set(&point, 'x'));
+        }
+        assert!(grid.set(&GridPoint::new(2, 2), 'x'));
+        assert_eq!(grid.get(&GridPoint::new(2, 2)), Some('x'));
+        assert_eq!(grid.to_string(), "abc\ndef\nghx\n");
+    }
+
+    #[test]
+    fn rows_and_columns_read_across_and_down() {
+        let grid = letters();
+        let rows = grid.rows().map(String::from_iter).collect::<Vec<_>>();
+        assert_eq!(rows, ["abc", "def", "ghi"]);
+        let columns = grid.columns().map(String::from_iter).collect::<Vec<_>>();
+        assert_eq!(columns, ["adg", "beh", "cfi"]);
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.get_bounds(), (3, 3));
+    }
+
+    #[test]
+    fn find_all_goes_row_by_row() {
+        let grid = Grid::new(vec![vec!['#', '.'], vec!['.', '#'], vec!['#', '#']]);
+        let points = [(0, 0), (1, 1), (2, 0), (2, 1)].map(|(i, j)| GridPoint::new(i, j));
+        assert_eq!(grid.find_all('#'), points);
+        assert_eq!(grid.find('.'), Some(GridPoint::new(0, 1)));
+        assert_eq!(grid.find('x'), None);
+    }
+}
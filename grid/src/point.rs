@@ -0,0 +1,98 @@
+use std::ops::{Add, Sub};
+
+use crate::Direction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GridPoint {
+    pub index1: i32,
+    pub index2: i32,
+}
+
+impl GridPoint {
+    pub fn new(index1: i32, index2: i32) -> GridPoint {
+        GridPoint { index1, index2 }
+    }
+
+    pub fn is_in_bounds(&self, bounds: (usize, usize)) -> bool {
+        self.index1 < bounds.0 as i32
+            && self.index2 < bounds.1 as i32
+            && self.index1 >= 0
+            && self.index2 >= 0
+    }
+
+    // The 4 points sharing a side with this one, in Direction::ALL order
+    pub fn neighbours(&self) -> impl Iterator<Item = GridPoint> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| *self + direction.step())
+    }
+
+    // The 8 points sharing a side or a corner with this one, clockwise from Up
+    pub fn all_neighbours(&self) -> impl Iterator<Item = GridPoint> + '_ {
+        Direction::ALL.into_iter().flat_map(move |direction| {
+            let side = *self + direction.step();
+            [side, side + direction.turn().step()]
+        })
+    }
+}
+
+impl Add<GridPoint> for GridPoint {
+    type Output = GridPoint;
+    fn add(self, rhs: GridPoint) -> Self::Output {
+        GridPoint {
+            index1: self.index1 + rhs.index1,
+            index2: self.index2 + rhs.index2,
+        }
+    }
+}
+
+impl Sub<GridPoint> for GridPoint {
+    type Output = GridPoint;
+    fn sub(self, rhs: GridPoint) -> Self::Output {
+        GridPoint {
+            index1: self.index1 - rhs.index1,
+            index2: self.index2 - rhs.index2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_go_clockwise_from_up() {
+        let point = GridPoint::new(0, 0);
+        let sides = point
+            .neighbours()
+            .map(|p| (p.index1, p.index2))
+            .collect::<Vec<_>>();
+        assert_eq!(sides, [(-1, 0), (0, 1), (1, 0), (0, -1)]);
+        let all = point
+            .all_neighbours()
+            .map(|p| (p.index1, p.index2))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all,
+            [
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn bounds_exclude_negative_and_far_edges() {
+        assert!(GridPoint::new(0, 0).is_in_bounds((2, 3)));
+        assert!(GridPoint::new(1, 2).is_in_bounds((2, 3)));
+        assert!(!GridPoint::new(2, 0).is_in_bounds((2, 3)));
+        assert!(!GridPoint::new(0, 3).is_in_bounds((2, 3)));
+        assert!(!GridPoint::new(-1, 0).is_in_bounds((2, 3)));
+    }
+}